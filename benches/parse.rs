@@ -0,0 +1,92 @@
+use std::hint::black_box;
+
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use fuzzy_date::{FuzzyDate, FuzzyDateRange};
+
+const DATES: &[&str] = &["2026", "2026-02", "2026-02-13", "02/2026", "02/13/2026"];
+const INVALID: &[&str] = &["2026-13", "2026-02-30", "02/XX/2026", "not a date"];
+const RANGES: &[&str] = &["2020/2026", "2020-03/2026-02-13"];
+
+fn bench_dates(c: &mut Criterion) {
+    let mut group = c.benchmark_group("date");
+    for input in DATES.iter().chain(INVALID) {
+        group.bench_function(format!("from_str/{input}"), |b| {
+            b.iter(|| black_box(input).parse::<FuzzyDate>());
+        });
+        group.bench_function(format!("parse_bytes/{input}"), |b| {
+            b.iter(|| FuzzyDate::parse_bytes(black_box(input.as_bytes())));
+        });
+    }
+    group.finish();
+}
+
+fn bench_ranges(c: &mut Criterion) {
+    let mut group = c.benchmark_group("range");
+    for input in RANGES {
+        group.bench_function(format!("from_str/{input}"), |b| {
+            b.iter(|| black_box(input).parse::<FuzzyDateRange>());
+        });
+        group.bench_function(format!("parse_bytes/{input}"), |b| {
+            b.iter(|| FuzzyDateRange::parse_bytes(black_box(input.as_bytes())));
+        });
+    }
+    group.finish();
+}
+
+/// A column of `n` values cycling through `DATES` and `INVALID`.
+fn column(n: usize) -> Vec<&'static str> {
+    DATES
+        .iter()
+        .chain(INVALID)
+        .copied()
+        .cycle()
+        .take(n)
+        .collect()
+}
+
+/// A column of `n` fixed-width ISO day values.
+fn iso_column(n: usize) -> Vec<String> {
+    (0..n)
+        .map(|i| format!("{:04}-{:02}-{:02}", 1900 + i % 200, 1 + i % 12, 1 + i % 28))
+        .collect()
+}
+
+fn bench_batch(c: &mut Criterion) {
+    const N: usize = 10_000;
+    let mut group = c.benchmark_group("batch");
+    group.throughput(Throughput::Elements(N as u64));
+
+    let mixed = column(N);
+    let iso = iso_column(N);
+    for (name, inputs) in [
+        ("mixed", mixed),
+        ("iso", iso.iter().map(String::as_str).collect()),
+    ] {
+        group.bench_function(format!("from_str/{name}"), |b| {
+            b.iter(|| {
+                black_box(&inputs)
+                    .iter()
+                    .map(|s| s.parse::<FuzzyDate>())
+                    .collect::<Vec<_>>()
+            });
+        });
+        group.bench_function(format!("parse_batch/{name}"), |b| {
+            b.iter(|| FuzzyDate::parse_batch(black_box(&inputs)));
+        });
+
+        let data = inputs.concat();
+        let offsets: Vec<usize> = std::iter::once(0)
+            .chain(inputs.iter().scan(0, |end, s| {
+                *end += s.len();
+                Some(*end)
+            }))
+            .collect();
+        group.bench_function(format!("parse_offsets/{name}"), |b| {
+            b.iter(|| FuzzyDate::parse_offsets(black_box(data.as_bytes()), black_box(&offsets)));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_dates, bench_ranges, bench_batch);
+criterion_main!(benches);
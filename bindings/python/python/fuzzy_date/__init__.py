@@ -0,0 +1,5 @@
+"""Dates known to the year, the month or the day."""
+
+from ._fuzzy_date import FuzzyDate, FuzzyDateRange, ParseError, RangeError
+
+__all__ = ["FuzzyDate", "FuzzyDateRange", "ParseError", "RangeError"]
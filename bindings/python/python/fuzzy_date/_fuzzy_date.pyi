@@ -0,0 +1,48 @@
+import datetime
+from typing import Literal, Optional, Union
+
+Precision = Literal["year", "month", "day"]
+DateLike = Union["FuzzyDate", datetime.date]
+
+class ParseError(ValueError): ...
+class RangeError(ValueError): ...
+
+class FuzzyDate:
+    def __init__(self, value: str) -> None: ...
+    @staticmethod
+    def from_date(date: datetime.date, precision: Precision = "day") -> FuzzyDate: ...
+    @property
+    def year(self) -> int: ...
+    @property
+    def month(self) -> Optional[int]: ...
+    @property
+    def day(self) -> Optional[int]: ...
+    @property
+    def precision(self) -> Precision: ...
+    def lower_bound(self) -> datetime.date: ...
+    def upper_bound(self) -> datetime.date: ...
+    def contains(self, other: DateLike) -> bool: ...
+    def __lt__(self, other: FuzzyDate) -> bool: ...
+    def __le__(self, other: FuzzyDate) -> bool: ...
+    def __gt__(self, other: FuzzyDate) -> bool: ...
+    def __ge__(self, other: FuzzyDate) -> bool: ...
+    def __hash__(self) -> int: ...
+
+class FuzzyDateRange:
+    def __init__(self, start: DateLike, end: DateLike) -> None: ...
+    @staticmethod
+    def parse(value: str) -> FuzzyDateRange: ...
+    @property
+    def start(self) -> FuzzyDate: ...
+    @property
+    def end(self) -> FuzzyDate: ...
+    def lower_bound(self) -> datetime.date: ...
+    def upper_bound(self) -> datetime.date: ...
+    def contains(self, date: DateLike) -> bool: ...
+    def overlaps(self, other: FuzzyDateRange) -> bool: ...
+    def is_within(self, other: FuzzyDateRange) -> bool: ...
+    def __lt__(self, other: FuzzyDateRange) -> bool: ...
+    def __le__(self, other: FuzzyDateRange) -> bool: ...
+    def __gt__(self, other: FuzzyDateRange) -> bool: ...
+    def __ge__(self, other: FuzzyDateRange) -> bool: ...
+    def __hash__(self) -> int: ...
@@ -0,0 +1,232 @@
+//! Python bindings for `fuzzy_date`.
+//!
+//! `FuzzyDate` and `FuzzyDateRange` are immutable Python classes that compare,
+//! hash and pickle like the Rust types. Parse failures raise `ParseError` and
+//! reversed or malformed ranges raise `RangeError`; both are `ValueError`s.
+
+// `#[pymethods]` receive `&self` and owned arguments whatever their size.
+#![allow(clippy::trivially_copy_pass_by_ref, clippy::needless_pass_by_value)]
+
+use pyo3::{
+    create_exception,
+    exceptions::PyValueError,
+    prelude::*,
+    types::{PyDate, PyDateAccess, PyType},
+};
+
+create_exception!(
+    fuzzy_date,
+    ParseError,
+    PyValueError,
+    "A value is not a valid fuzzy date."
+);
+create_exception!(
+    fuzzy_date,
+    RangeError,
+    PyValueError,
+    "A range is malformed or starts after it ends."
+);
+
+fn parse_error(err: fuzzy_date::ParseError) -> PyErr {
+    ParseError::new_err(err.to_string())
+}
+
+/// Errors in either endpoint surface as `ParseError`, as they do in Rust
+/// through `RangeError::ParseError`.
+fn range_error(err: fuzzy_date::RangeError) -> PyErr {
+    match err {
+        fuzzy_date::RangeError::ParseError(err) => parse_error(err),
+        err => RangeError::new_err(err.to_string()),
+    }
+}
+
+fn precision(name: &str) -> PyResult<fuzzy_date::Precision> {
+    match name {
+        "year" => Ok(fuzzy_date::Precision::Year),
+        "month" => Ok(fuzzy_date::Precision::Month),
+        "day" => Ok(fuzzy_date::Precision::Day),
+        _ => Err(PyValueError::new_err(format!(
+            "precision must be 'year', 'month' or 'day', not {name:?}"
+        ))),
+    }
+}
+
+fn to_date(py: Python<'_>, (year, month, day): (u16, u8, u8)) -> PyResult<Bound<'_, PyDate>> {
+    PyDate::new(py, i32::from(year), month, day)
+}
+
+fn from_date(date: &Bound<'_, PyDate>) -> PyResult<fuzzy_date::FuzzyDate> {
+    let year = u16::try_from(date.get_year())
+        .unwrap_or_else(|_| unreachable!("datetime.date years are 1-9999"));
+    fuzzy_date::FuzzyDate::from_columns(year, Some(date.get_month()), Some(date.get_day()))
+        .map_err(parse_error)
+}
+
+/// A `FuzzyDate`, or a `datetime.date` taken as a full date.
+#[derive(FromPyObject)]
+enum DateLike<'py> {
+    Fuzzy(FuzzyDate),
+    Date(Bound<'py, PyDate>),
+}
+
+impl DateLike<'_> {
+    fn resolve(&self) -> PyResult<fuzzy_date::FuzzyDate> {
+        match self {
+            Self::Fuzzy(date) => Ok(date.0),
+            Self::Date(date) => from_date(date),
+        }
+    }
+}
+
+/// A date known to the year, the month or the day.
+#[pyclass(module = "fuzzy_date", frozen, eq, ord, hash, from_py_object)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct FuzzyDate(fuzzy_date::FuzzyDate);
+
+#[pymethods]
+impl FuzzyDate {
+    /// Parses `YYYY`, `YYYY-MM`, `YYYY-MM-DD`, `MM/YYYY` or `MM/DD/YYYY`.
+    #[new]
+    fn new(value: &str) -> PyResult<Self> {
+        value.parse().map(Self).map_err(parse_error)
+    }
+
+    /// `date` truncated to `precision`: `"year"`, `"month"` or `"day"`.
+    #[staticmethod]
+    #[pyo3(signature = (date, precision = "day"))]
+    fn from_date(date: &Bound<'_, PyDate>, precision: &str) -> PyResult<Self> {
+        let precision = self::precision(precision)?;
+        Ok(Self(self::from_date(date)?.truncate(precision)))
+    }
+
+    #[getter]
+    const fn year(&self) -> u16 {
+        self.0.year().get()
+    }
+
+    #[getter]
+    fn month(&self) -> Option<u8> {
+        self.0.month().map(fuzzy_date::Month::get)
+    }
+
+    #[getter]
+    fn day(&self) -> Option<u8> {
+        self.0.day().map(fuzzy_date::Day::get)
+    }
+
+    /// `"year"`, `"month"` or `"day"`.
+    #[getter]
+    fn precision(&self) -> String {
+        self.0.precision().to_string()
+    }
+
+    /// The first day this date covers.
+    fn lower_bound<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDate>> {
+        to_date(py, self.0.lower_bound())
+    }
+
+    /// The last day this date covers.
+    fn upper_bound<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDate>> {
+        to_date(py, self.0.upper_bound_inclusive())
+    }
+
+    /// Whether every day of `other` falls within this date.
+    fn contains(&self, other: DateLike<'_>) -> PyResult<bool> {
+        Ok(self.0.contains(&other.resolve()?))
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("FuzzyDate('{}')", self.0)
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> (Bound<'py, PyType>, (String,)) {
+        (slf.get_type(), (slf.get().0.to_string(),))
+    }
+}
+
+/// An inclusive range between two fuzzy dates.
+#[pyclass(module = "fuzzy_date", frozen, eq, ord, hash, from_py_object)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct FuzzyDateRange(fuzzy_date::FuzzyDateRange);
+
+#[pymethods]
+impl FuzzyDateRange {
+    /// The range from `start` to `end`, each a `FuzzyDate` or `datetime.date`.
+    #[new]
+    fn new(start: DateLike<'_>, end: DateLike<'_>) -> PyResult<Self> {
+        fuzzy_date::FuzzyDateRange::new(start.resolve()?, end.resolve()?)
+            .map(Self)
+            .map_err(range_error)
+    }
+
+    /// Parses an ISO `start/end` range.
+    #[staticmethod]
+    fn parse(value: &str) -> PyResult<Self> {
+        value.parse().map(Self).map_err(range_error)
+    }
+
+    #[getter]
+    const fn start(&self) -> FuzzyDate {
+        FuzzyDate(self.0.start())
+    }
+
+    #[getter]
+    const fn end(&self) -> FuzzyDate {
+        FuzzyDate(self.0.end())
+    }
+
+    /// The first day of the range.
+    fn lower_bound<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDate>> {
+        to_date(py, self.0.lower_bound())
+    }
+
+    /// The last day of the range.
+    fn upper_bound<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDate>> {
+        to_date(py, self.0.upper_bound_inclusive())
+    }
+
+    /// Whether every day of `date` falls within the range.
+    fn contains(&self, date: DateLike<'_>) -> PyResult<bool> {
+        Ok(self.0.contains(&date.resolve()?))
+    }
+
+    /// Whether the two ranges share at least one day.
+    fn overlaps(&self, other: &Self) -> bool {
+        self.0.overlaps(&other.0)
+    }
+
+    /// Whether every day of this range falls within `other`.
+    fn is_within(&self, other: &Self) -> bool {
+        self.0.is_within(&other.0)
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "FuzzyDateRange(FuzzyDate('{}'), FuzzyDate('{}'))",
+            self.0.start(),
+            self.0.end()
+        )
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> (Bound<'py, PyType>, (FuzzyDate, FuzzyDate)) {
+        let (start, end) = slf.get().0.dates();
+        (slf.get_type(), (FuzzyDate(start), FuzzyDate(end)))
+    }
+}
+
+#[pymodule]
+fn _fuzzy_date(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<FuzzyDate>()?;
+    m.add_class::<FuzzyDateRange>()?;
+    m.add("ParseError", m.py().get_type::<ParseError>())?;
+    m.add("RangeError", m.py().get_type::<RangeError>())?;
+    Ok(())
+}
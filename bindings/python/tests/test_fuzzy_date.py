@@ -0,0 +1,95 @@
+import datetime
+import pickle
+import unittest
+
+from fuzzy_date import FuzzyDate, FuzzyDateRange, ParseError, RangeError
+
+
+class FuzzyDateTest(unittest.TestCase):
+    def test_parse(self):
+        date = FuzzyDate("08/15/1991")
+        self.assertEqual(str(date), "1991-08-15")
+        self.assertEqual(repr(date), "FuzzyDate('1991-08-15')")
+        self.assertEqual((date.year, date.month, date.day), (1991, 8, 15))
+        self.assertEqual(date.precision, "day")
+        self.assertEqual(FuzzyDate("2026").month, None)
+
+    def test_parse_errors(self):
+        with self.assertRaisesRegex(ParseError, "Invalid month: 13"):
+            FuzzyDate("2026-13")
+        with self.assertRaises(ValueError):
+            FuzzyDate("")
+
+    def test_comparison(self):
+        dates = [FuzzyDate(s) for s in ["2026-02-13", "2026", "2025-12", "2026-02"]]
+        self.assertEqual([str(d) for d in sorted(dates)], ["2025-12", "2026", "2026-02", "2026-02-13"])
+        self.assertEqual(FuzzyDate("2026-02"), FuzzyDate("02/2026"))
+        self.assertNotEqual(FuzzyDate("2026"), FuzzyDate("2026-01"))
+        self.assertLess(FuzzyDate("2026"), FuzzyDate("2026-01"))
+        self.assertEqual(len({FuzzyDate("2026-02"), FuzzyDate("02/2026")}), 1)
+
+    def test_bounds(self):
+        date = FuzzyDate("2024-02")
+        self.assertEqual(date.lower_bound(), datetime.date(2024, 2, 1))
+        self.assertEqual(date.upper_bound(), datetime.date(2024, 2, 29))
+
+    def test_contains(self):
+        year = FuzzyDate("2026")
+        self.assertTrue(year.contains(FuzzyDate("2026-02")))
+        self.assertTrue(year.contains(datetime.date(2026, 12, 31)))
+        self.assertFalse(FuzzyDate("2026-02").contains(year))
+
+    def test_from_date(self):
+        date = datetime.date(2026, 2, 13)
+        self.assertEqual(FuzzyDate.from_date(date), FuzzyDate("2026-02-13"))
+        self.assertEqual(FuzzyDate.from_date(date, "month"), FuzzyDate("2026-02"))
+        self.assertEqual(FuzzyDate.from_date(datetime.datetime(2026, 2, 13, 9), "year"), FuzzyDate("2026"))
+        with self.assertRaises(ValueError):
+            FuzzyDate.from_date(date, "week")
+
+    def test_pickle(self):
+        date = FuzzyDate("2026-02")
+        self.assertEqual(pickle.loads(pickle.dumps(date)), date)
+
+
+class FuzzyDateRangeTest(unittest.TestCase):
+    def test_parse(self):
+        span = FuzzyDateRange.parse("2020/2026-02")
+        self.assertEqual((span.start, span.end), (FuzzyDate("2020"), FuzzyDate("2026-02")))
+        self.assertEqual(str(span), "2020/2026-02")
+        self.assertEqual(repr(span), "FuzzyDateRange(FuzzyDate('2020'), FuzzyDate('2026-02'))")
+
+    def test_errors(self):
+        with self.assertRaisesRegex(RangeError, r"start \(2026\) is after end \(2020\)"):
+            FuzzyDateRange.parse("2026/2020")
+        with self.assertRaises(RangeError):
+            FuzzyDateRange.parse("2020")
+        with self.assertRaises(ParseError):
+            FuzzyDateRange.parse("2020/2026-13")
+        with self.assertRaises(RangeError):
+            FuzzyDateRange(FuzzyDate("2026"), datetime.date(2020, 1, 1))
+
+    def test_construction(self):
+        span = FuzzyDateRange(FuzzyDate("2020"), datetime.date(2026, 2, 13))
+        self.assertEqual(span, FuzzyDateRange.parse("2020/2026-02-13"))
+        self.assertEqual(span.lower_bound(), datetime.date(2020, 1, 1))
+        self.assertEqual(span.upper_bound(), datetime.date(2026, 2, 13))
+
+    def test_relations(self):
+        span = FuzzyDateRange.parse("2020/2026-02")
+        self.assertTrue(span.contains(FuzzyDate("2026-02")))
+        self.assertFalse(span.contains(datetime.date(2026, 3, 1)))
+        self.assertTrue(span.overlaps(FuzzyDateRange.parse("2026/2027")))
+        self.assertFalse(span.overlaps(FuzzyDateRange.parse("2027/2028")))
+        self.assertTrue(FuzzyDateRange.parse("2021/2022").is_within(span))
+
+    def test_comparison_and_pickle(self):
+        early, late = FuzzyDateRange.parse("2020/2021"), FuzzyDateRange.parse("2020/2022")
+        self.assertLess(early, late)
+        self.assertEqual(sorted([late, early]), [early, late])
+        self.assertEqual(hash(early), hash(FuzzyDateRange.parse("2020/2021")))
+        self.assertEqual(pickle.loads(pickle.dumps(late)), late)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,321 @@
+//! WebAssembly bindings for `fuzzy_date`.
+//!
+//! Exposes `FuzzyDate` and `FuzzyDateRange` to JavaScript along with free
+//! functions to validate and normalize strings. Failures are thrown, or for the
+//! `validate` functions returned, as `Error` objects whose `name` is
+//! `FuzzyDateParseError` or `FuzzyDateRangeError`, so they do not shadow the
+//! global `RangeError`, and whose `kind` and extra properties mirror the Rust
+//! variant.
+
+// `#[wasm_bindgen]` rejects `const fn` exports.
+#![allow(clippy::missing_const_for_fn)]
+
+use core::cmp::Ordering;
+
+use js_sys::{Error, Reflect};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TYPES: &str = r#"
+export type Precision = "year" | "month" | "day";
+
+export type FuzzyDateParseError = Error & { name: "FuzzyDateParseError" } & (
+  | { kind: "InvalidFormat"; offset?: number }
+  | { kind: "InvalidYear"; year: number }
+  | { kind: "InvalidMonth"; month: number }
+  | { kind: "InvalidDay"; year: number; month: number; day: number }
+  | { kind: "DayWithoutMonth"; day: number }
+  | { kind: "EmptyInput" }
+  | { kind: "TwoDigitYear"; year: number }
+  | { kind: "Unknown" }
+);
+
+export type FuzzyDateRangeError = Error & { name: "FuzzyDateRangeError" } & (
+  | { kind: "InvalidRange"; start: string; end: string }
+  | { kind: "ParseError"; cause: FuzzyDateParseError }
+  | { kind: "InvalidFormat"; offset?: number }
+  | { kind: "Unknown" }
+);
+"#;
+
+fn set(target: &Error, key: &str, value: impl Into<JsValue>) {
+    Reflect::set(target, &key.into(), &value.into())
+        .unwrap_or_else(|_| unreachable!("a new Error accepts any property"));
+}
+
+fn error(name: &str, kind: &str, message: &str) -> Error {
+    let error = Error::new(message);
+    error.set_name(name);
+    set(&error, "kind", kind);
+    error
+}
+
+fn parse_error(err: &fuzzy_date::ParseError) -> Error {
+    use fuzzy_date::ParseError;
+
+    let message = err.to_string();
+    match *err {
+        ParseError::InvalidFormat(_) => error("FuzzyDateParseError", "InvalidFormat", &message),
+        ParseError::InvalidFormatAt(offset) => {
+            let error = error("FuzzyDateParseError", "InvalidFormat", &message);
+            set(&error, "offset", offset);
+            error
+        }
+        ParseError::InvalidYear(year) => {
+            let error = error("FuzzyDateParseError", "InvalidYear", &message);
+            set(&error, "year", year);
+            error
+        }
+        ParseError::InvalidMonth(month) => {
+            let error = error("FuzzyDateParseError", "InvalidMonth", &message);
+            set(&error, "month", month);
+            error
+        }
+        ParseError::InvalidDay { month, day, year } => {
+            let error = error("FuzzyDateParseError", "InvalidDay", &message);
+            set(&error, "year", year);
+            set(&error, "month", month);
+            set(&error, "day", day);
+            error
+        }
+        ParseError::DayWithoutMonth { day } => {
+            let error = error("FuzzyDateParseError", "DayWithoutMonth", &message);
+            set(&error, "day", day);
+            error
+        }
+        ParseError::EmptyInput => error("FuzzyDateParseError", "EmptyInput", &message),
+        ParseError::TwoDigitYear(year) => {
+            let error = error("FuzzyDateParseError", "TwoDigitYear", &message);
+            set(&error, "year", year);
+            error
+        }
+        _ => error("FuzzyDateParseError", "Unknown", &message),
+    }
+}
+
+fn range_error(err: &fuzzy_date::RangeError) -> Error {
+    use fuzzy_date::RangeError;
+
+    let message = err.to_string();
+    match err {
+        RangeError::InvalidRange { start, end } => {
+            let error = error("FuzzyDateRangeError", "InvalidRange", &message);
+            set(&error, "start", start.to_string());
+            set(&error, "end", end.to_string());
+            error
+        }
+        RangeError::ParseError(cause) => {
+            let error = error("FuzzyDateRangeError", "ParseError", &message);
+            set(&error, "cause", parse_error(cause));
+            error
+        }
+        RangeError::InvalidFormat(_) => error("FuzzyDateRangeError", "InvalidFormat", &message),
+        RangeError::InvalidFormatAt(offset) => {
+            let error = error("FuzzyDateRangeError", "InvalidFormat", &message);
+            set(&error, "offset", *offset);
+            error
+        }
+        _ => error("FuzzyDateRangeError", "Unknown", &message),
+    }
+}
+
+fn iso_day((year, month, day): (u16, u8, u8)) -> String {
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+const fn compare(ordering: Ordering) -> i32 {
+    ordering as i32
+}
+
+/// A date known to the year, the month or the day.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy)]
+pub struct FuzzyDate(fuzzy_date::FuzzyDate);
+
+#[wasm_bindgen]
+impl FuzzyDate {
+    /// Parses `YYYY`, `YYYY-MM`, `YYYY-MM-DD`, `MM/YYYY` or `MM/DD/YYYY`.
+    ///
+    /// # Errors
+    /// Throws a `FuzzyDateParseError`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(input: &str) -> Result<Self, Error> {
+        input.parse().map(Self).map_err(|err| parse_error(&err))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn year(&self) -> u16 {
+        self.0.year().get()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn month(&self) -> Option<u8> {
+        self.0.month().map(fuzzy_date::Month::get)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn day(&self) -> Option<u8> {
+        self.0.day().map(fuzzy_date::Day::get)
+    }
+
+    #[wasm_bindgen(getter, unchecked_return_type = "Precision")]
+    pub fn precision(&self) -> String {
+        self.0.precision().to_string()
+    }
+
+    /// The first day this date covers, as `YYYY-MM-DD`.
+    #[wasm_bindgen(js_name = lowerBound)]
+    pub fn lower_bound(&self) -> String {
+        iso_day(self.0.lower_bound())
+    }
+
+    /// The last day this date covers, as `YYYY-MM-DD`.
+    #[wasm_bindgen(js_name = upperBound)]
+    pub fn upper_bound(&self) -> String {
+        iso_day(self.0.upper_bound_inclusive())
+    }
+
+    /// Whether every day of `other` falls within this date.
+    pub fn contains(&self, other: &Self) -> bool {
+        self.0.contains(&other.0)
+    }
+
+    /// `-1`, `0` or `1` as this date sorts before, with or after `other`.
+    pub fn compare(&self, other: &Self) -> i32 {
+        compare(self.0.cmp(&other.0))
+    }
+
+    pub fn equals(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+
+    /// The ISO form.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_iso(&self) -> String {
+        self.0.to_string()
+    }
+
+    #[wasm_bindgen(js_name = toJSON)]
+    pub fn to_json(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+/// An inclusive range between two fuzzy dates.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy)]
+pub struct FuzzyDateRange(fuzzy_date::FuzzyDateRange);
+
+#[wasm_bindgen]
+impl FuzzyDateRange {
+    /// # Errors
+    /// Throws a `FuzzyDateRangeError` if `start` is after `end`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(start: &FuzzyDate, end: &FuzzyDate) -> Result<Self, Error> {
+        fuzzy_date::FuzzyDateRange::new(start.0, end.0)
+            .map(Self)
+            .map_err(|err| range_error(&err))
+    }
+
+    /// Parses an ISO `start/end` range.
+    ///
+    /// # Errors
+    /// Throws a `FuzzyDateRangeError`.
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        input.parse().map(Self).map_err(|err| range_error(&err))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn start(&self) -> FuzzyDate {
+        FuzzyDate(self.0.start())
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn end(&self) -> FuzzyDate {
+        FuzzyDate(self.0.end())
+    }
+
+    /// The first day of the range, as `YYYY-MM-DD`.
+    #[wasm_bindgen(js_name = lowerBound)]
+    pub fn lower_bound(&self) -> String {
+        iso_day(self.0.lower_bound())
+    }
+
+    /// The last day of the range, as `YYYY-MM-DD`.
+    #[wasm_bindgen(js_name = upperBound)]
+    pub fn upper_bound(&self) -> String {
+        iso_day(self.0.upper_bound_inclusive())
+    }
+
+    /// Whether every day of `date` falls within the range.
+    pub fn contains(&self, date: &FuzzyDate) -> bool {
+        self.0.contains(&date.0)
+    }
+
+    /// Whether the two ranges share at least one day.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.0.overlaps(&other.0)
+    }
+
+    /// Whether every day of this range falls within `other`.
+    #[wasm_bindgen(js_name = isWithin)]
+    pub fn is_within(&self, other: &Self) -> bool {
+        self.0.is_within(&other.0)
+    }
+
+    /// `-1`, `0` or `1` as this range sorts before, with or after `other`.
+    pub fn compare(&self, other: &Self) -> i32 {
+        compare(self.0.cmp(&other.0))
+    }
+
+    pub fn equals(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+
+    /// The ISO `start/end` form.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_iso(&self) -> String {
+        self.0.to_string()
+    }
+
+    #[wasm_bindgen(js_name = toJSON)]
+    pub fn to_json(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+/// The reason `input` is not a valid date, or `undefined` if it is.
+#[wasm_bindgen(unchecked_return_type = "FuzzyDateParseError | undefined")]
+pub fn validate(input: &str) -> Option<Error> {
+    input
+        .parse::<fuzzy_date::FuzzyDate>()
+        .err()
+        .map(|err| parse_error(&err))
+}
+
+/// The reason `input` is not a valid range, or `undefined` if it is.
+#[wasm_bindgen(js_name = validateRange, unchecked_return_type = "FuzzyDateRangeError | undefined")]
+pub fn validate_range(input: &str) -> Option<Error> {
+    input
+        .parse::<fuzzy_date::FuzzyDateRange>()
+        .err()
+        .map(|err| range_error(&err))
+}
+
+/// Normalizes a date to its ISO form.
+///
+/// # Errors
+/// Throws a `FuzzyDateParseError`.
+#[wasm_bindgen]
+pub fn format(input: &str) -> Result<String, Error> {
+    FuzzyDate::new(input).map(|date| date.to_iso())
+}
+
+/// Normalizes a range to its ISO `start/end` form.
+///
+/// # Errors
+/// Throws a `FuzzyDateRangeError`.
+#[wasm_bindgen(js_name = formatRange)]
+pub fn format_range(input: &str) -> Result<String, Error> {
+    FuzzyDateRange::parse(input).map(|range| range.to_iso())
+}
@@ -0,0 +1,86 @@
+import assert from "node:assert/strict";
+import { test } from "node:test";
+
+import { FuzzyDate, FuzzyDateRange, format, formatRange, validate, validateRange } from "fuzzy-date";
+
+test("parses dates", () => {
+  const date = new FuzzyDate("08/15/1991");
+  assert.equal(date.toString(), "1991-08-15");
+  assert.deepEqual([date.year, date.month, date.day], [1991, 8, 15]);
+  assert.equal(date.precision, "day");
+  assert.equal(new FuzzyDate("2026").month, undefined);
+  assert.equal(JSON.stringify({ born: new FuzzyDate("2026-2") }), '{"born":"2026-02"}');
+});
+
+test("throws structured parse errors", () => {
+  assert.throws(() => new FuzzyDate("2026-13"), {
+    name: "FuzzyDateParseError",
+    kind: "InvalidMonth",
+    month: 13,
+    message: "Invalid month: 13 (must be 1-12)",
+  });
+  assert.throws(() => new FuzzyDate("2023-02-29"), {
+    kind: "InvalidDay",
+    year: 2023,
+    month: 2,
+    day: 29,
+  });
+  assert.throws(() => new FuzzyDate(""), { kind: "EmptyInput" });
+  assert.throws(() => new FuzzyDate("nope"), (err) => err instanceof Error && err.kind === "InvalidFormat");
+});
+
+test("validates and formats", () => {
+  assert.equal(validate("02/2026"), undefined);
+  assert.equal(validate("0000").kind, "InvalidYear");
+  assert.equal(format("02/13/2026"), "2026-02-13");
+  assert.throws(() => format("2026-00"), { name: "FuzzyDateParseError", kind: "InvalidMonth" });
+
+  assert.equal(validateRange("2020/2026"), undefined);
+  assert.equal(formatRange("2020-1/2026-2"), "2020-01/2026-02");
+});
+
+test("throws structured range errors", () => {
+  const reversed = validateRange("2026/2020");
+  assert.equal(reversed.name, "FuzzyDateRangeError");
+  assert.equal(reversed.kind, "InvalidRange");
+  assert.deepEqual([reversed.start, reversed.end], ["2026", "2020"]);
+
+  const nested = validateRange("2020/2026-13");
+  assert.equal(nested.kind, "ParseError");
+  assert.equal(nested.cause.kind, "InvalidMonth");
+
+  assert.equal(validateRange("2020").kind, "InvalidFormat");
+  assert.throws(() => new FuzzyDateRange(new FuzzyDate("2026"), new FuzzyDate("2020")), {
+    name: "FuzzyDateRangeError",
+    kind: "InvalidRange",
+  });
+});
+
+test("computes bounds", () => {
+  const date = new FuzzyDate("2024-02");
+  assert.deepEqual([date.lowerBound(), date.upperBound()], ["2024-02-01", "2024-02-29"]);
+  const range = FuzzyDateRange.parse("2020/2026-02");
+  assert.deepEqual([range.lowerBound(), range.upperBound()], ["2020-01-01", "2026-02-28"]);
+  assert.equal(range.start.toString(), "2020");
+});
+
+test("checks containment and overlap", () => {
+  const year = new FuzzyDate("2026");
+  assert.ok(year.contains(new FuzzyDate("2026-02-13")));
+  assert.ok(!new FuzzyDate("2026-02").contains(year));
+
+  const range = FuzzyDateRange.parse("2020/2026-02");
+  assert.ok(range.contains(new FuzzyDate("2026-02")));
+  assert.ok(!range.contains(new FuzzyDate("2026-03")));
+  assert.ok(range.overlaps(FuzzyDateRange.parse("2026/2027")));
+  assert.ok(!range.overlaps(FuzzyDateRange.parse("2027/2028")));
+  assert.ok(FuzzyDateRange.parse("2021/2022").isWithin(range));
+});
+
+test("orders like the Rust types", () => {
+  const dates = ["2026-02-13", "2026", "2025-12", "2026-02"].map((s) => new FuzzyDate(s));
+  dates.sort((a, b) => a.compare(b));
+  assert.deepEqual(dates.map(String), ["2025-12", "2026", "2026-02", "2026-02-13"]);
+  assert.ok(new FuzzyDate("2026-02").equals(new FuzzyDate("02/2026")));
+  assert.equal(FuzzyDateRange.parse("2020/2021").compare(FuzzyDateRange.parse("2020/2022")), -1);
+});
@@ -0,0 +1,47 @@
+//! Deserializing untrusted JSON and postcard never panics, and everything
+//! accepted serializes back to an equal value, in the default string form and
+//! through each `fuzzy_date::serde` adapter.
+
+#![no_main]
+
+use fuzzy_date::{FuzzyDate, FuzzyDateRange};
+use libfuzzer_sys::fuzz_target;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Adapters {
+    #[serde(default, with = "fuzzy_date::serde::object")]
+    object: Option<FuzzyDate>,
+    #[serde(default, with = "fuzzy_date::serde::columns")]
+    columns: Option<FuzzyDateRange>,
+    #[serde(default, with = "fuzzy_date::serde::compact")]
+    compact: Option<FuzzyDate>,
+}
+
+fn json_round_trip<T: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug>(data: &[u8]) {
+    if let Ok(value) = serde_json::from_slice::<T>(data) {
+        let json = serde_json::to_vec(&value).unwrap_or_default();
+        assert_eq!(serde_json::from_slice::<T>(&json).ok(), Some(value));
+    }
+}
+
+fn postcard_round_trip<T: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug>(data: &[u8]) {
+    if let Ok(value) = postcard::from_bytes::<T>(data) {
+        let bytes = postcard::to_allocvec(&value).unwrap_or_default();
+        assert_eq!(postcard::from_bytes::<T>(&bytes).ok(), Some(value));
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(value) = serde_json::from_slice::<FuzzyDate>(data) {
+        let text: String = serde_json::from_slice(data).unwrap_or_default();
+        assert_eq!(text.parse(), Ok(value));
+    }
+    json_round_trip::<FuzzyDate>(data);
+    json_round_trip::<FuzzyDateRange>(data);
+    json_round_trip::<Adapters>(data);
+
+    postcard_round_trip::<FuzzyDate>(data);
+    postcard_round_trip::<FuzzyDateRange>(data);
+    postcard_round_trip::<Adapters>(data);
+});
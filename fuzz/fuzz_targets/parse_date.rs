@@ -0,0 +1,53 @@
+//! `FuzzyDate::from_str` never panics, round-trips through `Display`, agrees
+//! with `parse_bytes` and the single-format parsers, and reads the
+//! month-first spelling of every date the same as its ISO spelling.
+
+#![no_main]
+
+use fuzzy_date::{DateFormat, FuzzyDate, Parser};
+use libfuzzer_sys::fuzz_target;
+
+/// `parse_bytes` trims only ASCII whitespace, so it agrees with `FromStr`
+/// unless the input holds other whitespace.
+fn only_ascii_whitespace(input: &str) -> bool {
+    !input
+        .chars()
+        .any(|c| c.is_whitespace() && !c.is_ascii_whitespace())
+}
+
+/// The month-first spelling of `date`; a bare year has none.
+fn month_first(date: FuzzyDate) -> Option<String> {
+    match date.to_columns() {
+        (year, Some(month), None) => Some(format!("{month:02}/{year:04}")),
+        (year, Some(month), Some(day)) => Some(format!("{month:02}/{day:02}/{year:04}")),
+        (_, None, _) => None,
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    let from_bytes = FuzzyDate::parse_bytes(data);
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    let parsed = input.parse::<FuzzyDate>();
+    if only_ascii_whitespace(input) {
+        assert_eq!(parsed.as_ref().ok(), from_bytes.as_ref().ok(), "{input:?}");
+    }
+    let Ok(date) = parsed else {
+        return;
+    };
+
+    assert_eq!(date.to_string().parse(), Ok(date), "{input:?}");
+
+    let format = if input.contains('/') {
+        DateFormat::MonthFirst
+    } else {
+        DateFormat::Iso
+    };
+    assert_eq!(Parser::new(format).parse(input), Ok(date), "{input:?}");
+
+    if let Some(slash) = month_first(date) {
+        assert_eq!(slash.parse(), Ok(date), "{input:?} as {slash:?}");
+    }
+});
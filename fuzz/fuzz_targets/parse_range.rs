@@ -0,0 +1,33 @@
+//! `FuzzyDateRange::from_str` never panics, round-trips through `Display`,
+//! agrees with `parse_bytes`, and only produces ordered ranges whose ends
+//! parse on their own.
+
+#![no_main]
+
+use fuzzy_date::{FuzzyDate, FuzzyDateRange};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let from_bytes = FuzzyDateRange::parse_bytes(data);
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    let parsed = input.parse::<FuzzyDateRange>();
+    if !input
+        .chars()
+        .any(|c| c.is_whitespace() && !c.is_ascii_whitespace())
+    {
+        assert_eq!(parsed.as_ref().ok(), from_bytes.as_ref().ok(), "{input:?}");
+    }
+    let Ok(range) = parsed else {
+        return;
+    };
+
+    assert!(range.start() <= range.end(), "{input:?}");
+    assert_eq!(range.to_string().parse(), Ok(range), "{input:?}");
+
+    let (start, end) = input.trim().split_once('/').unwrap_or_default();
+    assert_eq!(start.parse::<FuzzyDate>(), Ok(range.start()), "{input:?}");
+    assert_eq!(end.parse::<FuzzyDate>(), Ok(range.end()), "{input:?}");
+});
@@ -0,0 +1,143 @@
+//! `arbitrary` integration.
+//!
+//! `Arbitrary` draws from the default [`DateDistribution`];
+//! [`DateDistribution::arbitrary_date`] and
+//! [`DateDistribution::arbitrary_range`] draw from a custom one. Drawing never
+//! fails on exhausted input: missing bytes read as the smallest value.
+
+use ::arbitrary::{Arbitrary, Result, Unstructured, size_hint};
+
+use crate::{
+    Day, FuzzyDate, FuzzyDateRange, MAX_MONTH, MAX_YEAR, MIN_DAY, Month, Year,
+    distribution::{self, DateDistribution},
+};
+
+impl DateDistribution {
+    /// Draws a date from this distribution.
+    ///
+    /// # Errors
+    /// Returns the errors of `Unstructured::int_in_range`.
+    pub fn arbitrary_date(&self, u: &mut Unstructured<'_>) -> Result<FuzzyDate> {
+        let year = u.int_in_range(self.years())?;
+        let pick = u.int_in_range(0..=distribution::weight_total(self) - 1)?;
+        let month = u.int_in_range(1..=MAX_MONTH)?;
+        let day = u.int_in_range(MIN_DAY..=31)?;
+        Ok(distribution::date(self, year, pick, month, day))
+    }
+
+    /// Draws a range whose ends both come from this distribution.
+    ///
+    /// # Errors
+    /// Returns the errors of `Unstructured::int_in_range`.
+    pub fn arbitrary_range(&self, u: &mut Unstructured<'_>) -> Result<FuzzyDateRange> {
+        let a = self.arbitrary_date(u)?;
+        let b = self.arbitrary_date(u)?;
+        Ok(distribution::range(a, b))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Year {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Self::new(u.int_in_range(1..=MAX_YEAR)?).map_err(|_| ::arbitrary::Error::IncorrectFormat)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u16::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Month {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Self::new(u.int_in_range(1..=MAX_MONTH)?).map_err(|_| ::arbitrary::Error::IncorrectFormat)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u8::size_hint(depth)
+    }
+}
+
+/// Any day that is valid in some month, `1..=31`.
+impl<'a> Arbitrary<'a> for Day {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Self::try_from(u.int_in_range(MIN_DAY..=31)?)
+            .map_err(|_| ::arbitrary::Error::IncorrectFormat)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u8::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for FuzzyDate {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        DateDistribution::new().arbitrary_date(u)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and_all(&[
+            u16::size_hint(depth),
+            // The precision draw needs as many bytes as the weight total.
+            (1, Some(8)),
+            u8::size_hint(depth),
+            u8::size_hint(depth),
+        ])
+    }
+}
+
+impl<'a> Arbitrary<'a> for FuzzyDateRange {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        DateDistribution::new().arbitrary_range(u)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(FuzzyDate::size_hint(depth), FuzzyDate::size_hint(depth))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Precision, test_utils::year};
+
+    fn bytes() -> Vec<u8> {
+        (0..4096_u32)
+            .map(|i| i.wrapping_mul(2_654_435_761).to_be_bytes()[0])
+            .collect()
+    }
+
+    #[test]
+    fn test_dates_are_valid() {
+        let input = bytes();
+        let mut u = Unstructured::new(&input);
+        while !u.is_empty() {
+            let date = FuzzyDate::arbitrary(&mut u).expect("expected a date");
+            assert_eq!(date.to_string().parse::<FuzzyDate>(), Ok(date));
+            let range = FuzzyDateRange::arbitrary(&mut u).expect("expected a range");
+            assert!(range.start() <= range.end());
+        }
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let mut u = Unstructured::new(&[]);
+        let date = FuzzyDate::arbitrary(&mut u).expect("expected a date");
+        assert_eq!(date, crate::test_utils::fuzzy_year(1));
+        assert!(Year::arbitrary(&mut u).is_ok());
+        assert!(Month::arbitrary(&mut u).is_ok());
+        assert!(Day::arbitrary(&mut u).is_ok());
+    }
+
+    #[test]
+    fn test_distribution() {
+        let dist = DateDistribution::new()
+            .with_years(year(2000), year(2004))
+            .with_precision_weights(0, 0, 1);
+        let input = bytes();
+        let mut u = Unstructured::new(&input);
+        while !u.is_empty() {
+            let date = dist.arbitrary_date(&mut u).expect("expected a date");
+            assert!((2000..=2004).contains(&date.year().get()), "{date}");
+            assert_eq!(date.precision(), Precision::Day);
+        }
+    }
+}
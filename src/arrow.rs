@@ -0,0 +1,459 @@
+//! Arrow integration.
+//!
+//! A `FuzzyDate` column is either a struct array of `year: UInt16`,
+//! `month: UInt8` and `day: UInt8` with nulls mirroring `to_columns`, or a
+//! `UInt32` array of `PackedFuzzyDate` bits. A `FuzzyDateRange` column is a
+//! struct array of `start` and `end` date structs, or a `UInt64` array of
+//! `FuzzyDateRange::to_key`. Fields built by `arrow_field` and
+//! `arrow_packed_field` carry an extension type name, which Parquet keeps in
+//! the stored Arrow schema.
+
+use std::{collections::HashMap, fmt, sync::Arc};
+
+use arrow_array::{
+    Array, ArrayRef, PrimitiveArray, StructArray, UInt8Array, UInt16Array, UInt32Array,
+    UInt64Array,
+    cast::AsArray,
+    types::{UInt8Type, UInt16Type, UInt32Type, UInt64Type},
+};
+use arrow_buffer::NullBuffer;
+use arrow_schema::{ArrowError, DataType, Field, Fields};
+
+use crate::{FuzzyDate, FuzzyDateRange, PackedFuzzyDate};
+
+/// Field metadata key for the extension type name.
+const EXTENSION_NAME_KEY: &str = "ARROW:extension:name";
+
+fn date_fields() -> Fields {
+    Fields::from(vec![
+        Field::new("year", DataType::UInt16, false),
+        Field::new("month", DataType::UInt8, true),
+        Field::new("day", DataType::UInt8, true),
+    ])
+}
+
+fn range_fields() -> Fields {
+    Fields::from(vec![
+        Field::new("start", DataType::Struct(date_fields()), false),
+        Field::new("end", DataType::Struct(date_fields()), false),
+    ])
+}
+
+fn extension_field(name: String, data_type: DataType, nullable: bool, extension: &str) -> Field {
+    Field::new(name, data_type, nullable).with_metadata(HashMap::from([(
+        EXTENSION_NAME_KEY.to_owned(),
+        extension.to_owned(),
+    )]))
+}
+
+/// Validity buffer for `valid`, or `None` when every row is valid.
+fn null_buffer(valid: Vec<bool>) -> Option<NullBuffer> {
+    valid.contains(&false).then(|| NullBuffer::from(valid))
+}
+
+fn invalid_row(row: usize, err: impl fmt::Display) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!("row {row}: {err}"))
+}
+
+fn unexpected_type(target: &str, expected: &str, found: &DataType) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!(
+        "expected a {expected} array for {target}, found {found}"
+    ))
+}
+
+impl FuzzyDate {
+    /// Extension type name on fields from [`arrow_field`](Self::arrow_field)
+    /// and [`arrow_packed_field`](Self::arrow_packed_field).
+    pub const ARROW_EXTENSION_NAME: &'static str = "fuzzy_date.date";
+
+    /// Field for the struct array built by [`to_arrow`](Self::to_arrow).
+    pub fn arrow_field(name: impl Into<String>, nullable: bool) -> Field {
+        extension_field(
+            name.into(),
+            DataType::Struct(date_fields()),
+            nullable,
+            Self::ARROW_EXTENSION_NAME,
+        )
+    }
+
+    /// Field for the `UInt32` array built by
+    /// [`to_arrow_packed`](Self::to_arrow_packed).
+    pub fn arrow_packed_field(name: impl Into<String>, nullable: bool) -> Field {
+        extension_field(
+            name.into(),
+            DataType::UInt32,
+            nullable,
+            Self::ARROW_EXTENSION_NAME,
+        )
+    }
+
+    /// Builds a struct array of `year`, `month` and `day` from dates or
+    /// optional dates, with `None` as a null row.
+    pub fn to_arrow<T: Copy + Into<Option<Self>>>(dates: &[T]) -> StructArray {
+        let mut years = Vec::with_capacity(dates.len());
+        let mut months = Vec::with_capacity(dates.len());
+        let mut days = Vec::with_capacity(dates.len());
+        let mut valid = Vec::with_capacity(dates.len());
+        for &date in dates {
+            let date: Option<Self> = date.into();
+            let (year, month, day) = date.map_or((None, None, None), |date| {
+                let (year, month, day) = date.to_columns();
+                (Some(year), month, day)
+            });
+            years.push(year);
+            months.push(month);
+            days.push(day);
+            valid.push(date.is_some());
+        }
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(UInt16Array::from(years)),
+            Arc::new(UInt8Array::from(months)),
+            Arc::new(UInt8Array::from(days)),
+        ];
+        StructArray::new(date_fields(), columns, null_buffer(valid))
+    }
+
+    /// Builds a `UInt32` array of `PackedFuzzyDate` bits, which sort like
+    /// `Ord`, with `None` as a null row.
+    pub fn to_arrow_packed<T: Copy + Into<Option<Self>>>(dates: &[T]) -> UInt32Array {
+        dates
+            .iter()
+            .map(|&date| {
+                let date: Option<Self> = date.into();
+                date.map(|date| PackedFuzzyDate::new(date).to_bits())
+            })
+            .collect()
+    }
+
+    /// Reads a struct array from [`to_arrow`](Self::to_arrow) or a `UInt32`
+    /// array from [`to_arrow_packed`](Self::to_arrow_packed). Null rows
+    /// become `None`.
+    ///
+    /// # Errors
+    /// Returns `ArrowError::InvalidArgumentError` for any other array type,
+    /// missing or mistyped struct children, a null year in a valid row, or
+    /// values that are not a valid date.
+    pub fn from_arrow(array: &dyn Array) -> Result<Vec<Option<Self>>, ArrowError> {
+        match array.data_type() {
+            DataType::UInt32 => array
+                .as_primitive::<UInt32Type>()
+                .iter()
+                .enumerate()
+                .map(|(row, bits)| {
+                    bits.map(|bits| {
+                        PackedFuzzyDate::from_bits(bits)
+                            .map(PackedFuzzyDate::get)
+                            .map_err(|err| invalid_row(row, err))
+                    })
+                    .transpose()
+                })
+                .collect(),
+            DataType::Struct(_) => {
+                let columns = DateColumns::new(array.as_struct())?;
+                (0..array.len()).map(|row| columns.get(row)).collect()
+            }
+            other => Err(unexpected_type("FuzzyDate", "Struct or UInt32", other)),
+        }
+    }
+}
+
+/// Typed children of a date struct array.
+struct DateColumns<'a> {
+    array: &'a StructArray,
+    years: &'a PrimitiveArray<UInt16Type>,
+    months: &'a PrimitiveArray<UInt8Type>,
+    days: &'a PrimitiveArray<UInt8Type>,
+}
+
+impl<'a> DateColumns<'a> {
+    fn new(array: &'a StructArray) -> Result<Self, ArrowError> {
+        let years = child(array, "year")?.as_primitive_opt();
+        let months = child(array, "month")?.as_primitive_opt();
+        let days = child(array, "day")?.as_primitive_opt();
+        let (Some(years), Some(months), Some(days)) = (years, months, days) else {
+            return Err(unexpected_type(
+                "FuzzyDate",
+                "Struct<year: UInt16, month: UInt8, day: UInt8>",
+                array.data_type(),
+            ));
+        };
+        Ok(Self {
+            array,
+            years,
+            months,
+            days,
+        })
+    }
+
+    /// The date in `row`, or `None` if the row is null.
+    fn get(&self, row: usize) -> Result<Option<FuzzyDate>, ArrowError> {
+        if self.array.is_null(row) {
+            return Ok(None);
+        }
+        if self.years.is_null(row) {
+            return Err(invalid_row(row, "null year"));
+        }
+        let month = self.months.is_valid(row).then(|| self.months.value(row));
+        let day = self.days.is_valid(row).then(|| self.days.value(row));
+        FuzzyDate::from_columns(self.years.value(row), month, day)
+            .map(Some)
+            .map_err(|err| invalid_row(row, err))
+    }
+}
+
+fn child<'a>(array: &'a StructArray, name: &str) -> Result<&'a ArrayRef, ArrowError> {
+    array
+        .column_by_name(name)
+        .ok_or_else(|| ArrowError::InvalidArgumentError(format!("missing struct field `{name}`")))
+}
+
+fn struct_child<'a>(array: &'a StructArray, name: &str) -> Result<&'a StructArray, ArrowError> {
+    let child = child(array, name)?;
+    child
+        .as_struct_opt()
+        .ok_or_else(|| unexpected_type("FuzzyDateRange", "Struct", child.data_type()))
+}
+
+impl FuzzyDateRange {
+    /// Extension type name on fields from [`arrow_field`](Self::arrow_field)
+    /// and [`arrow_packed_field`](Self::arrow_packed_field).
+    pub const ARROW_EXTENSION_NAME: &'static str = "fuzzy_date.range";
+
+    /// Field for the struct array built by [`to_arrow`](Self::to_arrow).
+    pub fn arrow_field(name: impl Into<String>, nullable: bool) -> Field {
+        extension_field(
+            name.into(),
+            DataType::Struct(range_fields()),
+            nullable,
+            Self::ARROW_EXTENSION_NAME,
+        )
+    }
+
+    /// Field for the `UInt64` array built by
+    /// [`to_arrow_packed`](Self::to_arrow_packed).
+    pub fn arrow_packed_field(name: impl Into<String>, nullable: bool) -> Field {
+        extension_field(
+            name.into(),
+            DataType::UInt64,
+            nullable,
+            Self::ARROW_EXTENSION_NAME,
+        )
+    }
+
+    /// Builds a struct array of `start` and `end` date structs from ranges
+    /// or optional ranges, with `None` as a null row.
+    pub fn to_arrow<T: Copy + Into<Option<Self>>>(ranges: &[T]) -> StructArray {
+        let mut starts = Vec::with_capacity(ranges.len());
+        let mut ends = Vec::with_capacity(ranges.len());
+        let mut valid = Vec::with_capacity(ranges.len());
+        for &range in ranges {
+            let range: Option<Self> = range.into();
+            starts.push(range.map(|range| range.start()));
+            ends.push(range.map(|range| range.end()));
+            valid.push(range.is_some());
+        }
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(FuzzyDate::to_arrow(&starts)),
+            Arc::new(FuzzyDate::to_arrow(&ends)),
+        ];
+        StructArray::new(range_fields(), columns, null_buffer(valid))
+    }
+
+    /// Builds a `UInt64` array of big-endian [`to_key`](Self::to_key)
+    /// values, which sort like `Ord`, with `None` as a null row.
+    pub fn to_arrow_packed<T: Copy + Into<Option<Self>>>(ranges: &[T]) -> UInt64Array {
+        ranges
+            .iter()
+            .map(|&range| {
+                let range: Option<Self> = range.into();
+                range.map(|range| u64::from_be_bytes(range.to_key()))
+            })
+            .collect()
+    }
+
+    /// Reads a struct array from [`to_arrow`](Self::to_arrow) or a `UInt64`
+    /// array from [`to_arrow_packed`](Self::to_arrow_packed). Null rows
+    /// become `None`.
+    ///
+    /// # Errors
+    /// Returns `ArrowError::InvalidArgumentError` for any other array type,
+    /// missing or mistyped struct children, a null start or end in a valid
+    /// row, or values that are not a valid range.
+    pub fn from_arrow(array: &dyn Array) -> Result<Vec<Option<Self>>, ArrowError> {
+        match array.data_type() {
+            DataType::UInt64 => array
+                .as_primitive::<UInt64Type>()
+                .iter()
+                .enumerate()
+                .map(|(row, key)| {
+                    key.map(|key| {
+                        Self::from_key(key.to_be_bytes()).map_err(|err| invalid_row(row, err))
+                    })
+                    .transpose()
+                })
+                .collect(),
+            DataType::Struct(_) => {
+                let array = array.as_struct();
+                let starts = DateColumns::new(struct_child(array, "start")?)?;
+                let ends = DateColumns::new(struct_child(array, "end")?)?;
+                (0..array.len())
+                    .map(|row| {
+                        // Children of a null row may hold arbitrary values
+                        if array.is_null(row) {
+                            return Ok(None);
+                        }
+                        match (starts.get(row)?, ends.get(row)?) {
+                            (Some(start), Some(end)) => Self::new(start, end)
+                                .map(Some)
+                                .map_err(|err| invalid_row(row, err)),
+                            _ => Err(invalid_row(row, "null start or end")),
+                        }
+                    })
+                    .collect()
+            }
+            other => Err(unexpected_type("FuzzyDateRange", "Struct or UInt64", other)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::RecordBatch;
+    use arrow_schema::Schema;
+    use bytes::Bytes;
+    use parquet::arrow::{ArrowWriter, arrow_reader::ParquetRecordBatchReaderBuilder};
+
+    use super::*;
+    use crate::test_utils::{fuzzy_day, fuzzy_month, fuzzy_year};
+
+    fn dates() -> Vec<Option<FuzzyDate>> {
+        vec![
+            Some(fuzzy_year(1991)),
+            None,
+            Some(fuzzy_month(2026, 2)),
+            Some(fuzzy_day(2024, 2, 29)),
+        ]
+    }
+
+    fn ranges() -> Vec<Option<FuzzyDateRange>> {
+        vec![
+            None,
+            FuzzyDateRange::new(fuzzy_year(2020), fuzzy_day(2026, 2, 13)).ok(),
+            FuzzyDateRange::new(fuzzy_month(2001, 3), fuzzy_month(2001, 3)).ok(),
+        ]
+    }
+
+    #[test]
+    fn test_struct_round_trip() {
+        let array = FuzzyDate::to_arrow(&dates());
+        assert_eq!(array.null_count(), 1);
+        let months = array.column(1).as_primitive::<UInt8Type>();
+        assert_eq!(
+            months.iter().collect::<Vec<_>>(),
+            [None, None, Some(2), Some(2)]
+        );
+        assert_eq!(FuzzyDate::from_arrow(&array).ok(), Some(dates()));
+
+        let dense = [fuzzy_year(2020), fuzzy_day(2020, 1, 1)];
+        let array = FuzzyDate::to_arrow(&dense);
+        assert!(array.nulls().is_none());
+        let restored = FuzzyDate::from_arrow(&array).expect("expected dates");
+        assert_eq!(restored, dense.map(Some));
+
+        let array = FuzzyDateRange::to_arrow(&ranges());
+        assert_eq!(FuzzyDateRange::from_arrow(&array).ok(), Some(ranges()));
+    }
+
+    #[test]
+    fn test_packed_round_trip() {
+        let array = FuzzyDate::to_arrow_packed(&dates());
+        assert_eq!(array.null_count(), 1);
+        assert_eq!(FuzzyDate::from_arrow(&array).ok(), Some(dates()));
+
+        let array = FuzzyDateRange::to_arrow_packed(&ranges());
+        assert_eq!(FuzzyDateRange::from_arrow(&array).ok(), Some(ranges()));
+    }
+
+    #[test]
+    fn test_invalid_arrays() {
+        let packed = UInt32Array::from(vec![Some(0)]);
+        assert!(FuzzyDate::from_arrow(&packed).is_err());
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(UInt16Array::from(vec![2023])),
+            Arc::new(UInt8Array::from(vec![Some(2)])),
+            Arc::new(UInt8Array::from(vec![Some(29)])),
+        ];
+        let array = StructArray::new(date_fields(), columns, None);
+        let err = FuzzyDate::from_arrow(&array).expect_err("expected invalid day");
+        assert!(err.to_string().contains("row 0"), "{err}");
+
+        let wrong = UInt16Array::from(vec![2023]);
+        assert!(FuzzyDate::from_arrow(&wrong).is_err());
+        assert!(FuzzyDateRange::from_arrow(&packed).is_err());
+
+        let reversed = FuzzyDateRange::to_arrow(&ranges());
+        let (_, columns, nulls) = reversed.into_parts();
+        let swapped = StructArray::new(
+            range_fields(),
+            vec![Arc::clone(&columns[1]), Arc::clone(&columns[0])],
+            nulls,
+        );
+        assert!(FuzzyDateRange::from_arrow(&swapped).is_err());
+    }
+
+    #[test]
+    fn test_parquet_round_trip() {
+        let schema = Arc::new(Schema::new(vec![
+            FuzzyDate::arrow_field("born", true),
+            FuzzyDate::arrow_packed_field("born_packed", true),
+            FuzzyDateRange::arrow_field("active", true),
+            FuzzyDateRange::arrow_packed_field("active_packed", true),
+        ]));
+        let dates = dates();
+        let mut ranges = ranges();
+        ranges.push(None);
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(FuzzyDate::to_arrow(&dates)),
+            Arc::new(FuzzyDate::to_arrow_packed(&dates)),
+            Arc::new(FuzzyDateRange::to_arrow(&ranges)),
+            Arc::new(FuzzyDateRange::to_arrow_packed(&ranges)),
+        ];
+        let batch = RecordBatch::try_new(Arc::clone(&schema), columns).expect("expected batch");
+
+        let mut buffer = Vec::new();
+        let mut writer =
+            ArrowWriter::try_new(&mut buffer, schema, None).expect("expected parquet writer");
+        writer.write(&batch).expect("expected write");
+        writer.close().expect("expected close");
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buffer))
+            .expect("expected parquet reader")
+            .build()
+            .expect("expected reader");
+        let batches: Vec<RecordBatch> = reader.collect::<Result<_, _>>().expect("expected batches");
+        assert_eq!(batches.len(), 1);
+        let read = &batches[0];
+
+        for (index, field) in read.schema().fields().iter().enumerate() {
+            let extension = field.metadata().get(EXTENSION_NAME_KEY).map(String::as_str);
+            let expected = if index < 2 {
+                FuzzyDate::ARROW_EXTENSION_NAME
+            } else {
+                FuzzyDateRange::ARROW_EXTENSION_NAME
+            };
+            assert_eq!(extension, Some(expected), "{}", field.name());
+        }
+        for column in &read.columns()[..2] {
+            assert_eq!(FuzzyDate::from_arrow(column).ok().as_ref(), Some(&dates));
+        }
+        for column in &read.columns()[2..] {
+            assert_eq!(
+                FuzzyDateRange::from_arrow(column).ok().as_ref(),
+                Some(&ranges)
+            );
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use crate::{
+    ByteParseError, FuzzyDate, ParseError,
+    prelude::*,
+    types::{Day, Month, Year},
+};
+
+/// Output of [`FuzzyDate::parse_batch_split`]: parsed values aligned with the
+/// inputs, and the errors for the inputs that failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedBatch {
+    values: Vec<Option<FuzzyDate>>,
+    errors: Vec<(usize, ParseError)>,
+}
+
+impl ParsedBatch {
+    /// One entry per input; `None` where parsing failed.
+    pub fn values(&self) -> &[Option<FuzzyDate>] {
+        &self.values
+    }
+
+    /// Index and error of every input that failed, in input order.
+    pub fn errors(&self) -> &[(usize, ParseError)] {
+        &self.errors
+    }
+
+    /// Returns `true` if every input parsed.
+    pub const fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Splits into the aligned values and the errors.
+    pub fn into_parts(self) -> (Vec<Option<FuzzyDate>>, Vec<(usize, ParseError)>) {
+        (self.values, self.errors)
+    }
+}
+
+impl FuzzyDate {
+    /// Parses every input, returning one result per input in the same order.
+    ///
+    /// Fixed-width ISO values (`YYYY`, `YYYY-MM`, `YYYY-MM-DD`) take a
+    /// branch-light fast path that checks and decodes all digits of a value at
+    /// once within a `u64`; anything else falls back to `FromStr`, so the
+    /// results are identical to parsing each input individually.
+    pub fn parse_batch<S: AsRef<str>>(inputs: &[S]) -> Vec<Result<Self, ParseError>> {
+        inputs
+            .iter()
+            .map(|input| {
+                let input = input.as_ref();
+                fast_iso(input.as_bytes()).map_or_else(|| input.parse(), Ok)
+            })
+            .collect()
+    }
+
+    /// Like [`parse_batch`](Self::parse_batch), but separates the valid
+    /// values from the errors.
+    pub fn parse_batch_split<S: AsRef<str>>(inputs: &[S]) -> ParsedBatch {
+        let mut values = Vec::with_capacity(inputs.len());
+        let mut errors = Vec::new();
+        for (i, result) in Self::parse_batch(inputs).into_iter().enumerate() {
+            match result {
+                Ok(date) => values.push(Some(date)),
+                Err(err) => {
+                    values.push(None);
+                    errors.push((i, err));
+                }
+            }
+        }
+        ParsedBatch { values, errors }
+    }
+
+    /// Parses every value of a variable-width string buffer, as used by Arrow
+    /// `Utf8`/`LargeUtf8` arrays: value `i` is `data[offsets[i]..offsets[i + 1]]`.
+    ///
+    /// Uses the same fast path as [`parse_batch`](Self::parse_batch), falling
+    /// back to [`parse_bytes`](Self::parse_bytes), so nothing is allocated
+    /// beyond the output. Byte offsets in errors are relative to each value; a
+    /// value whose offsets are out of bounds or decreasing is reported as
+    /// `ByteParseError::InvalidFormat(0)`.
+    pub fn parse_offsets<O>(data: &[u8], offsets: &[O]) -> Vec<Result<Self, ByteParseError>>
+    where
+        O: Copy + TryInto<usize>,
+    {
+        offsets
+            .windows(2)
+            .map(|pair| {
+                let (Ok(start), Ok(end)) = (pair[0].try_into(), pair[1].try_into()) else {
+                    return Err(ByteParseError::InvalidFormat(0));
+                };
+                let value = data
+                    .get(start..end)
+                    .ok_or(ByteParseError::InvalidFormat(0))?;
+                fast_iso(value).map_or_else(|| Self::parse_bytes(value), Ok)
+            })
+            .collect()
+    }
+}
+
+/// `0x01` repeated in every byte lane.
+const LANES: u64 = u64::from_le_bytes([0x01; 8]);
+/// High bit of every byte lane.
+const HIGH_BITS: u64 = LANES * 0x80;
+
+/// Checks that every byte lane selected by `digit_lanes` holds an ASCII digit,
+/// and every lane in `dash_lanes` holds `-`.
+const fn lanes_match(word: u64, digit_lanes: u64, dash_lanes: u64) -> bool {
+    // Digits become 0..=9; adding 0x76 sets the lane's high bit for anything
+    // larger, and bytes >= 0x80 already have it set.
+    let values = word ^ (LANES * b'0' as u64);
+    let overflow = values.wrapping_add(LANES * 0x76);
+    let digits_ok = (values | overflow) & HIGH_BITS & digit_lanes == 0;
+    let dashes_ok = (word ^ (LANES * b'-' as u64)) & dash_lanes == 0;
+    digits_ok && dashes_ok
+}
+
+/// Byte mask covering lane `i` of a little-endian word.
+const fn lane(i: u32) -> u64 {
+    0xFF << (i * 8)
+}
+
+/// Decodes `YYYY`, `YYYY-MM` or `YYYY-MM-DD` with exact widths.
+///
+/// Returns `None` for anything else, including out-of-range values, so the
+/// caller can fall back to the scalar parser for an exact error.
+fn fast_iso(b: &[u8]) -> Option<FuzzyDate> {
+    const YEAR: u64 = lane(0) | lane(1) | lane(2) | lane(3);
+    const MONTH: u64 = lane(5) | lane(6);
+
+    let mut buf = [b'0'; 8];
+    let head = b.len().min(8);
+    buf[..head].copy_from_slice(&b[..head]);
+    let word = u64::from_le_bytes(buf);
+
+    let digit = |i: usize| u16::from(b[i] - b'0');
+    let year = || Year::new(digit(0) * 1000 + digit(1) * 100 + digit(2) * 10 + digit(3)).ok();
+    #[allow(clippy::cast_possible_truncation)] // at most 99
+    let pair = |i: usize| (digit(i) * 10 + digit(i + 1)) as u8;
+
+    match b.len() {
+        4 if lanes_match(word, YEAR, 0) => Some(FuzzyDate::Year { year: year()? }),
+        7 if lanes_match(word, YEAR | MONTH, lane(4)) => Some(FuzzyDate::Month {
+            year: year()?,
+            month: Month::new(pair(5)).ok()?,
+        }),
+        10 if lanes_match(word, YEAR | MONTH, lane(4) | lane(7))
+            && b[8].is_ascii_digit()
+            && b[9].is_ascii_digit() =>
+        {
+            let year = year()?;
+            let month = Month::new(pair(5)).ok()?;
+            let day = Day::new(pair(8), year.get(), month.get()).ok()?;
+            Some(FuzzyDate::Day { year, month, day })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{fuzzy_day, fuzzy_month, fuzzy_year};
+
+    const INPUTS: &[&str] = &[
+        "2026",
+        "2026-02",
+        "2026-02-13",
+        "0000",
+        "2026-13",
+        "2026-02-29",
+        "2024-02-29",
+        "02/13/2026",
+        " 2026-02-13 ",
+        "2026-2-13",
+        "2026-02-1x",
+        "2026/02/13",
+        "20a6-02-13",
+        "2026-\u{e9}",
+        "",
+    ];
+
+    #[test]
+    fn test_fast_path() {
+        assert_eq!(fast_iso(b"2026"), Some(fuzzy_year(2026)));
+        assert_eq!(fast_iso(b"2026-02"), Some(fuzzy_month(2026, 2)));
+        assert_eq!(fast_iso(b"2026-02-13"), Some(fuzzy_day(2026, 2, 13)));
+        assert_eq!(fast_iso(b"9999-12-31"), Some(fuzzy_day(9999, 12, 31)));
+
+        // Not fixed-width ISO, or out of range: left to the scalar parser
+        for input in [
+            "0000",
+            "2026-13",
+            "2026-02-30",
+            "2026-2-13",
+            "02/2026",
+            "2026:02",
+        ] {
+            assert_eq!(fast_iso(input.as_bytes()), None, "input: {input:?}");
+        }
+    }
+
+    #[test]
+    fn test_fast_path_rejects_non_digits() {
+        // Every byte just outside the digit range, and high bytes, in every lane
+        for pos in [0, 1, 2, 3, 5, 6, 8, 9] {
+            for bad in [b'/', b':', 0x80, 0xB0, 0xFF] {
+                let mut input = *b"2026-02-13";
+                input[pos] = bad;
+                assert_eq!(fast_iso(&input), None, "byte {bad:#x} at {pos}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_batch_matches_from_str() {
+        let results = FuzzyDate::parse_batch(INPUTS);
+        assert_eq!(results.len(), INPUTS.len());
+        for (input, result) in INPUTS.iter().zip(results) {
+            assert_eq!(result, input.parse::<FuzzyDate>(), "input: {input:?}");
+        }
+    }
+
+    #[test]
+    fn test_parse_batch_split() {
+        let batch = FuzzyDate::parse_batch_split(&["2026", "bad", "02/2026", ""]);
+        assert_eq!(
+            batch.values(),
+            &[
+                Some(fuzzy_year(2026)),
+                None,
+                Some(fuzzy_month(2026, 2)),
+                None
+            ]
+        );
+        assert_eq!(batch.errors().len(), 2);
+        assert_eq!(batch.errors()[0].0, 1);
+        assert_eq!(batch.errors()[1], (3, ParseError::EmptyInput));
+        assert!(!batch.is_complete());
+
+        let (values, errors) = FuzzyDate::parse_batch_split(&["2026"]).into_parts();
+        assert_eq!(values, vec![Some(fuzzy_year(2026))]);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_parse_offsets_matches_parse_bytes() {
+        let data: String = INPUTS.concat();
+        let mut offsets = vec![0i32];
+        for input in INPUTS {
+            let last = offsets[offsets.len() - 1];
+            offsets.push(last + i32::try_from(input.len()).expect("expected small input"));
+        }
+
+        let results = FuzzyDate::parse_offsets(data.as_bytes(), &offsets);
+        assert_eq!(results.len(), INPUTS.len());
+        for (input, result) in INPUTS.iter().zip(results) {
+            assert_eq!(
+                result,
+                FuzzyDate::parse_bytes(input.as_bytes()),
+                "input: {input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_offsets_invalid_offsets() {
+        let results = FuzzyDate::parse_offsets(b"2026", &[0i64, 4, 2, 10, -1]);
+        assert_eq!(
+            results,
+            vec![
+                Ok(fuzzy_year(2026)),
+                Err(ByteParseError::InvalidFormat(0)),
+                Err(ByteParseError::InvalidFormat(0)),
+                Err(ByteParseError::InvalidFormat(0)),
+            ]
+        );
+        assert!(FuzzyDate::parse_offsets::<i32>(b"", &[]).is_empty());
+    }
+}
@@ -0,0 +1,622 @@
+//! `fuzzy-date`: inspect, compare and convert fuzzy dates from the command line.
+//!
+//! Values are parsed as a date first and, failing that, as an ISO `start/end`
+//! range, so `02/2026` is February 2026 while `2020/2026` is a range. Commands
+//! print one result per line and report failures on stderr. The exit status is
+//! 0 on success, 1 if any value failed or a test was false, and 2 for usage
+//! errors.
+
+use std::{
+    fs::File,
+    io::{self, BufWriter, Read, Write},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use clap::{Args, Parser as _, Subcommand, ValueEnum};
+use fuzzy_date::{
+    CsvColumn, CsvNormalizer, DateFormat, FuzzyDate, FuzzyDateRange, Parser, Precision, RangeError,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, clap::Parser)]
+#[command(
+    name = "fuzzy-date",
+    version,
+    about = "Inspect, compare and convert fuzzy dates"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Print each value in normalized ISO form with its precision
+    Parse {
+        #[command(flatten)]
+        input: Input,
+        /// Dates, or ISO `start/end` ranges
+        #[arg(required = true)]
+        values: Vec<String>,
+    },
+    /// Print the first and last day each value covers
+    Bounds {
+        #[command(flatten)]
+        input: Input,
+        /// Dates, or ISO `start/end` ranges
+        #[arg(required = true)]
+        values: Vec<String>,
+    },
+    /// Test whether OUTER covers every day of INNER
+    Contains {
+        #[command(flatten)]
+        input: Input,
+        /// Date or range that should cover INNER
+        outer: String,
+        /// Date or range to look for
+        inner: String,
+    },
+    /// Test whether two values share at least one day
+    Overlaps {
+        #[command(flatten)]
+        input: Input,
+        /// Date or range
+        first: String,
+        /// Date or range
+        second: String,
+    },
+    /// Print each value in another format
+    Convert {
+        #[command(flatten)]
+        input: Input,
+        /// Output format
+        #[arg(long, short, value_enum)]
+        to: OutputFormat,
+        /// Dates, or ISO `start/end` ranges
+        #[arg(required = true)]
+        values: Vec<String>,
+    },
+    /// Check a CSV column, report invalid rows by line number and summarize
+    Validate {
+        #[command(flatten)]
+        input: Input,
+        /// Column name, or zero-based index with --no-headers
+        #[arg(long, short)]
+        column: String,
+        /// The first row is data, not column names
+        #[arg(long)]
+        no_headers: bool,
+        /// Field delimiter
+        #[arg(long, short, default_value_t = ',')]
+        delimiter: char,
+        /// Accept blank cells
+        #[arg(long)]
+        allow_empty: bool,
+        /// CSV file to read; standard input if omitted
+        file: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug, Args)]
+struct Input {
+    /// Format of date values; ranges are always ISO `start/end`
+    #[arg(long, short, value_enum, default_value_t = InputFormat::Auto)]
+    format: InputFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum InputFormat {
+    /// ISO or US month-first, as accepted by `FromStr`
+    Auto,
+    /// `YYYY`, `YYYY-MM`, `YYYY-MM-DD`
+    Iso,
+    /// EDTF level 0, which for these values is the ISO form
+    Edtf,
+    /// `MM/YYYY`, `MM/DD/YYYY`
+    MonthFirst,
+    /// `MM/YYYY`, `DD/MM/YYYY`
+    DayFirst,
+    /// `MM.YYYY`, `DD.MM.YYYY`
+    Dotted,
+    /// `YYYY`, `YYYYMMDD`
+    Basic,
+    /// `Feb 2026`, `13 Feb 2026`, `February 13, 2026`
+    MonthName,
+    /// `[year, month, day]` JSON arrays, as written by `--to columns`
+    Columns,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    /// `YYYY-MM-DD`, `start/end`
+    Iso,
+    /// EDTF level 0, which for these values is the ISO form
+    Edtf,
+    /// `MM/DD/YYYY`, `MM/YYYY` or `YYYY`; not available for ranges
+    Slash,
+    /// `[year, month, day]` JSON arrays, a pair of them for ranges
+    Columns,
+}
+
+impl InputFormat {
+    /// The `DateFormat` this reads, or `None` for `FromStr` and JSON columns.
+    const fn date_format(self) -> Option<DateFormat> {
+        match self {
+            Self::Auto | Self::Columns => None,
+            Self::Iso | Self::Edtf => Some(DateFormat::Iso),
+            Self::MonthFirst => Some(DateFormat::MonthFirst),
+            Self::DayFirst => Some(DateFormat::DayFirst),
+            Self::Dotted => Some(DateFormat::Dotted),
+            Self::Basic => Some(DateFormat::Basic),
+            Self::MonthName => Some(DateFormat::MonthName),
+        }
+    }
+
+    fn parse_date(self, s: &str) -> Result<FuzzyDate, String> {
+        if self == Self::Columns {
+            return serde_json::from_str(s)
+                .map(|DateColumns(date)| date)
+                .map_err(|err| err.to_string());
+        }
+        self.date_format()
+            .map_or_else(|| s.parse(), |format| Parser::new(format).parse(s))
+            .map_err(|err| err.to_string())
+    }
+
+    fn parse_range(self, s: &str) -> Result<FuzzyDateRange, String> {
+        if self == Self::Columns {
+            return serde_json::from_str(s)
+                .map(|RangeColumns(range)| range)
+                .map_err(|err| err.to_string());
+        }
+        s.parse::<FuzzyDateRange>().map_err(|err| err.to_string())
+    }
+}
+
+/// A date or range in the `columns` serde representation.
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+struct DateColumns(#[serde(with = "fuzzy_date::serde::columns")] FuzzyDate);
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+struct RangeColumns(#[serde(with = "fuzzy_date::serde::columns")] FuzzyDateRange);
+
+fn to_json(value: &impl Serialize) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| unreachable!("columns always serialize"))
+}
+
+/// A command-line value: a date, or failing that a range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Value {
+    Date(FuzzyDate),
+    Range(FuzzyDateRange),
+}
+
+impl Value {
+    fn parse(s: &str, format: InputFormat) -> Result<Self, String> {
+        let date_error = match format.parse_date(s) {
+            Ok(date) => return Ok(Self::Date(date)),
+            Err(err) => err,
+        };
+        match format.parse_range(s) {
+            Ok(range) => Ok(Self::Range(range)),
+            // Report why the range failed only if it was shaped like one
+            Err(err) if range_shaped(s, format) => Err(err),
+            Err(_) => Err(date_error),
+        }
+    }
+
+    /// The days this value covers, as a range.
+    fn span(self) -> FuzzyDateRange {
+        match self {
+            Self::Date(date) => FuzzyDateRange::new(date, date)
+                .unwrap_or_else(|_| unreachable!("a date is never after itself")),
+            Self::Range(range) => range,
+        }
+    }
+
+    fn precision(self) -> String {
+        match self {
+            Self::Date(date) => date.precision().to_string(),
+            Self::Range(range) => {
+                let (start, end) = (range.start().precision(), range.end().precision());
+                if start == end {
+                    format!("{start} range")
+                } else {
+                    format!("{start}/{end} range")
+                }
+            }
+        }
+    }
+
+    fn convert(self, to: OutputFormat) -> Result<String, String> {
+        match (self, to) {
+            (Self::Date(date), OutputFormat::Iso | OutputFormat::Edtf) => Ok(date.to_string()),
+            (Self::Range(range), OutputFormat::Iso | OutputFormat::Edtf) => Ok(range.to_string()),
+            (Self::Date(date), OutputFormat::Slash) => Ok(slash(date)),
+            (Self::Range(_), OutputFormat::Slash) => {
+                Err("ranges have no slash form; use iso or edtf".to_owned())
+            }
+            (Self::Date(date), OutputFormat::Columns) => Ok(to_json(&DateColumns(date))),
+            (Self::Range(range), OutputFormat::Columns) => Ok(to_json(&RangeColumns(range))),
+        }
+    }
+}
+
+fn range_shaped(s: &str, format: InputFormat) -> bool {
+    if format == InputFormat::Columns {
+        return s.trim_start().starts_with("[[");
+    }
+    !matches!(
+        s.parse::<FuzzyDateRange>(),
+        Err(RangeError::InvalidFormat(_))
+    )
+}
+
+/// Month-first form; a year alone is written as `YYYY`, which `FromStr` reads.
+fn slash(date: FuzzyDate) -> String {
+    let (year, month, day) = date.to_columns();
+    match (date.precision(), month, day) {
+        (Precision::Day, Some(month), Some(day)) => format!("{month:02}/{day:02}/{year:04}"),
+        (Precision::Month, Some(month), _) => format!("{month:02}/{year:04}"),
+        _ => format!("{year:04}"),
+    }
+}
+
+fn iso_day((year, month, day): (u16, u8, u8)) -> String {
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Runs `command`, writing results to `out` and failures to `err`, and
+/// returns whether every value succeeded and every test held.
+fn run(command: Command, out: &mut impl Write, err: &mut impl Write) -> io::Result<bool> {
+    match command {
+        Command::Parse { input, values } => each(&values, input.format, out, err, |value| {
+            Ok(format!("{}\t{}", display(value), value.precision()))
+        }),
+        Command::Bounds { input, values } => each(&values, input.format, out, err, |value| {
+            let span = value.span();
+            Ok(format!(
+                "{}\t{}",
+                iso_day(span.lower_bound()),
+                iso_day(span.upper_bound_inclusive())
+            ))
+        }),
+        Command::Contains {
+            input,
+            outer,
+            inner,
+        } => test(&outer, &inner, input.format, out, err, |outer, inner| {
+            let (outer, inner) = (outer.span(), inner.span());
+            outer.contains(&inner.start()) && outer.contains(&inner.end())
+        }),
+        Command::Overlaps {
+            input,
+            first,
+            second,
+        } => test(&first, &second, input.format, out, err, |first, second| {
+            first.span().overlaps(&second.span())
+        }),
+        Command::Convert { input, to, values } => {
+            each(&values, input.format, out, err, |value| value.convert(to))
+        }
+        Command::Validate {
+            input,
+            column,
+            no_headers,
+            delimiter,
+            allow_empty,
+            file,
+        } => {
+            let Ok(delimiter) = u8::try_from(delimiter) else {
+                writeln!(err, "delimiter must be a single-byte character")?;
+                return Ok(false);
+            };
+            let reader: Box<dyn Read> = match file {
+                Some(path) => match File::open(&path) {
+                    Ok(file) => Box::new(file),
+                    Err(error) => {
+                        writeln!(err, "{}: {error}", path.display())?;
+                        return Ok(false);
+                    }
+                },
+                None => Box::new(io::stdin().lock()),
+            };
+            if input.format == InputFormat::Columns {
+                writeln!(err, "validate does not read the columns format")?;
+                return Ok(false);
+            }
+            // A number names a column by position only without headers
+            let column = match column.parse() {
+                Ok(index) if no_headers => CsvColumn::Index(index),
+                _ => CsvColumn::Name(column),
+            };
+            let mut normalizer = CsvNormalizer::new(column)
+                .with_headers(!no_headers)
+                .with_delimiter(delimiter)
+                .with_blanks_allowed(allow_empty);
+            if let Some(format) = input.format.date_format() {
+                normalizer = normalizer.with_parser(Parser::new(format));
+            }
+            validate(reader, &normalizer, out, err)
+        }
+    }
+}
+
+fn display(value: Value) -> String {
+    match value {
+        Value::Date(date) => date.to_string(),
+        Value::Range(range) => range.to_string(),
+    }
+}
+
+/// Applies `f` to every value, printing results in order and continuing past
+/// failures.
+fn each(
+    values: &[String],
+    format: InputFormat,
+    out: &mut impl Write,
+    err: &mut impl Write,
+    f: impl Fn(Value) -> Result<String, String>,
+) -> io::Result<bool> {
+    let mut ok = true;
+    for input in values {
+        match Value::parse(input, format).and_then(&f) {
+            Ok(line) => writeln!(out, "{line}")?,
+            Err(error) => {
+                writeln!(err, "{input}: {error}")?;
+                ok = false;
+            }
+        }
+    }
+    Ok(ok)
+}
+
+/// Prints `true` or `false` for `f` applied to two values.
+fn test(
+    a: &str,
+    b: &str,
+    format: InputFormat,
+    out: &mut impl Write,
+    err: &mut impl Write,
+    f: impl Fn(Value, Value) -> bool,
+) -> io::Result<bool> {
+    let mut parse = |input: &str| -> io::Result<Option<Value>> {
+        match Value::parse(input, format) {
+            Ok(value) => Ok(Some(value)),
+            Err(error) => {
+                writeln!(err, "{input}: {error}")?;
+                Ok(None)
+            }
+        }
+    };
+    let (Some(a), Some(b)) = (parse(a)?, parse(b)?) else {
+        return Ok(false);
+    };
+    let holds = f(a, b);
+    writeln!(out, "{holds}")?;
+    Ok(holds)
+}
+
+/// Reports every invalid cell as `line N: "value": error` on `out` and the
+/// run's `CsvSummary` on `err`, discarding the normalized CSV.
+fn validate(
+    reader: impl Read,
+    normalizer: &CsvNormalizer,
+    out: &mut impl Write,
+    err: &mut impl Write,
+) -> io::Result<bool> {
+    let mut written = Ok(());
+    let result = normalizer.run(reader, io::sink(), |row| {
+        if written.is_ok() {
+            written = writeln!(out, "{row}");
+        }
+    });
+    written?;
+    out.flush()?;
+    match result {
+        Ok(summary) => {
+            writeln!(err, "{summary}")?;
+            Ok(summary.failed() == 0)
+        }
+        Err(error) => {
+            writeln!(err, "{error}")?;
+            Ok(false)
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let mut out = BufWriter::new(io::stdout().lock());
+    let mut err = io::stderr().lock();
+    let result = run(cli.command, &mut out, &mut err).and_then(|ok| out.flush().map(|()| ok));
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        // A closed pipe, such as `fuzzy-date parse ... | head`, is not an error
+        Err(error) if error.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(error) => {
+            let _ = writeln!(err, "fuzzy-date: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_args(args: &[&str]) -> (bool, String, String) {
+        let cli = Cli::try_parse_from(std::iter::once("fuzzy-date").chain(args.iter().copied()))
+            .expect("expected valid arguments");
+        let (mut out, mut err) = (Vec::new(), Vec::new());
+        let ok = run(cli.command, &mut out, &mut err).expect("expected writes to succeed");
+        (
+            ok,
+            String::from_utf8(out).expect("expected UTF-8 output"),
+            String::from_utf8(err).expect("expected UTF-8 errors"),
+        )
+    }
+
+    fn validate_csv(input: &str, normalizer: &CsvNormalizer) -> (bool, String, String) {
+        let (mut out, mut err) = (Vec::new(), Vec::new());
+        let ok = validate(input.as_bytes(), normalizer, &mut out, &mut err)
+            .expect("expected writes to succeed");
+        (
+            ok,
+            String::from_utf8(out).expect("expected UTF-8 output"),
+            String::from_utf8(err).expect("expected UTF-8 errors"),
+        )
+    }
+
+    #[test]
+    fn test_parse() {
+        let (ok, out, err) = run_args(&["parse", " 2026-2 ", "08/15/1991", "2020/2026-02"]);
+        assert!(ok, "{err}");
+        assert_eq!(
+            out,
+            "2026-02\tmonth\n1991-08-15\tday\n2020/2026-02\tyear/month range\n"
+        );
+
+        let (ok, out, err) = run_args(&["parse", "2026-13", "2026"]);
+        assert!(!ok);
+        assert_eq!(out, "2026\tyear\n");
+        assert!(err.starts_with("2026-13: Invalid month: 13"), "{err}");
+    }
+
+    #[test]
+    fn test_value_prefers_dates() {
+        assert!(matches!(
+            Value::parse("02/2026", InputFormat::Auto),
+            Ok(Value::Date(_))
+        ));
+        assert!(matches!(
+            Value::parse("2020/2026", InputFormat::Auto),
+            Ok(Value::Range(_))
+        ));
+        let err = Value::parse("2026/2020", InputFormat::Auto).expect_err("expected an error");
+        assert!(err.contains("start (2026) is after end (2020)"), "{err}");
+        let err = Value::parse("2026-02-30", InputFormat::Auto).expect_err("expected an error");
+        assert!(err.contains("Invalid day 30"), "{err}");
+    }
+
+    #[test]
+    fn test_bounds() {
+        let (ok, out, _) = run_args(&["bounds", "2024-02", "2020/2021-06"]);
+        assert!(ok);
+        assert_eq!(out, "2024-02-01\t2024-02-29\n2020-01-01\t2021-06-30\n");
+    }
+
+    #[test]
+    fn test_contains_and_overlaps() {
+        assert_eq!(
+            run_args(&["contains", "2026", "2026-02-13"]),
+            (true, "true\n".to_owned(), String::new())
+        );
+        assert_eq!(
+            run_args(&["contains", "2026-02", "2026/2027"]),
+            (false, "false\n".to_owned(), String::new())
+        );
+        assert!(run_args(&["overlaps", "2020/2026-02", "2026"]).0);
+        assert!(!run_args(&["overlaps", "2020/2025", "2026"]).0);
+
+        let (ok, out, err) = run_args(&["contains", "2026", "nope"]);
+        assert!(!ok);
+        assert!(out.is_empty());
+        assert!(err.starts_with("nope: "), "{err}");
+    }
+
+    #[test]
+    fn test_convert() {
+        let (ok, out, _) = run_args(&["convert", "--to", "slash", "2026-02-13", "2026-02", "2026"]);
+        assert!(ok);
+        assert_eq!(out, "02/13/2026\n02/2026\n2026\n");
+
+        let (ok, out, _) = run_args(&["convert", "-t", "columns", "2026-02", "2020/2026-02-13"]);
+        assert!(ok);
+        assert_eq!(out, "[2026,2,null]\n[[2020,null,null],[2026,2,13]]\n");
+
+        let (ok, out, _) = run_args(&[
+            "convert",
+            "-f",
+            "columns",
+            "-t",
+            "edtf",
+            "[2026,2,null]",
+            "[[2020,null,null],[2026,2,13]]",
+        ]);
+        assert!(ok);
+        assert_eq!(out, "2026-02\n2020/2026-02-13\n");
+
+        let (ok, out, _) = run_args(&["convert", "-f", "day-first", "-t", "iso", "13/02/2026"]);
+        assert!(ok);
+        assert_eq!(out, "2026-02-13\n");
+
+        let (ok, _, err) = run_args(&["convert", "-t", "slash", "2020/2026"]);
+        assert!(!ok);
+        assert!(err.contains("no slash form"), "{err}");
+    }
+
+    #[test]
+    fn test_validate() {
+        let input = "id,born\n1,1991-08\n2,\"1991-13\"\n3,08/15/1991\n4,\n";
+        let (ok, out, err) = validate_csv(input, &CsvNormalizer::new("born"));
+        assert!(!ok);
+        assert_eq!(
+            out,
+            "line 3: \"1991-13\": Invalid month: 13 (must be 1-12)\nline 5: \"\": Empty date string\n"
+        );
+        assert_eq!(
+            err,
+            "4 rows: 2 parsed (1 month, 1 day), 2 failed (1 empty, 1 invalid month)\n"
+        );
+
+        let normalizer = CsvNormalizer::new(0).with_headers(false);
+        let (ok, out, err) = validate_csv("1991\n2026-02\n", &normalizer);
+        assert!(ok, "{out}");
+        assert_eq!(err, "2 rows: 2 parsed (1 year, 1 month), 0 failed\n");
+
+        let (ok, _, err) = validate_csv(input, &CsvNormalizer::new("died"));
+        assert!(!ok);
+        assert_eq!(err, "No column \"died\" in the CSV header\n");
+    }
+
+    #[test]
+    fn test_validate_options() {
+        let dir = std::env::temp_dir().join(format!("fuzzy-date-cli-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("expected temporary directory");
+        let path = dir.join("people.csv");
+        std::fs::write(&path, "1;13/02/2026\n2;\n3;02/13/2026\n").expect("expected test file");
+        let file = path.to_str().expect("expected UTF-8 path");
+
+        let (ok, out, err) = run_args(&[
+            "validate",
+            "-f",
+            "day-first",
+            "-c",
+            "1",
+            "--no-headers",
+            "-d",
+            ";",
+            "--allow-empty",
+            file,
+        ]);
+        assert!(!ok);
+        assert_eq!(
+            out,
+            "line 3: \"02/13/2026\": Invalid month: 13 (must be 1-12)\n"
+        );
+        assert_eq!(
+            err,
+            "3 rows: 1 parsed (1 day), 1 blank, 1 failed (1 invalid month)\n"
+        );
+
+        let (ok, _, err) = run_args(&["validate", "-f", "columns", "-c", "born", file]);
+        assert!(!ok);
+        assert_eq!(err, "validate does not read the columns format\n");
+        std::fs::remove_dir_all(&dir).expect("expected cleanup");
+    }
+}
@@ -0,0 +1,448 @@
+use crate::{
+    FuzzyDate, FuzzyDateRange, ParseError, RangeError, range,
+    types::{self, Day, Month, Year},
+};
+
+/// `?` for `const fn`, where the `Try` operator is unavailable. Only for
+/// results whose error type already matches.
+macro_rules! tri {
+    ($result:expr) => {
+        match $result {
+            Ok(value) => value,
+            Err(err) => return Err(err),
+        }
+    };
+}
+
+/// Allocation-free counterpart of [`ParseError`], returned by
+/// [`FuzzyDate::parse_bytes`].
+///
+/// Format errors carry the byte offset of the first offending byte instead of
+/// a copy of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum ByteParseError {
+    /// The input format is invalid at the given byte offset.
+    #[error("Invalid date format at byte {0}")]
+    InvalidFormat(usize),
+
+    /// The year value is out of valid range (1..=9999).
+    #[error("Invalid year: {0}")]
+    InvalidYear(u16),
+
+    /// The month value is out of valid range (1..=12).
+    #[error("Invalid month: {0}")]
+    InvalidMonth(u8),
+
+    /// The day value is invalid for the given month and year.
+    #[error("Invalid day {day} for month {year}-{month:02}")]
+    InvalidDay { month: u8, day: u8, year: u16 },
+
+    /// The input is empty.
+    #[error("Empty date string")]
+    EmptyInput,
+}
+
+/// Allocation-free counterpart of [`RangeError`], returned by
+/// [`FuzzyDateRange::parse_bytes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum ByteRangeError {
+    /// Start date is after end date.
+    #[error("Invalid date range: start ({start}) is after end ({end})")]
+    InvalidRange { start: FuzzyDate, end: FuzzyDate },
+
+    /// Error parsing a date; offsets are relative to the whole range input.
+    #[error(transparent)]
+    ParseError(#[from] ByteParseError),
+
+    /// Missing or extra range separator at the given byte offset.
+    #[error("Invalid range format at byte {0}")]
+    InvalidFormat(usize),
+}
+
+impl From<ByteParseError> for ParseError {
+    fn from(err: ByteParseError) -> Self {
+        match err {
+            ByteParseError::InvalidFormat(at) => Self::InvalidFormatAt(at),
+            ByteParseError::InvalidYear(year) => Self::InvalidYear(year),
+            ByteParseError::InvalidMonth(month) => Self::InvalidMonth(month),
+            ByteParseError::InvalidDay { month, day, year } => {
+                Self::InvalidDay { month, day, year }
+            }
+            ByteParseError::EmptyInput => Self::EmptyInput,
+        }
+    }
+}
+
+impl From<ByteRangeError> for RangeError {
+    fn from(err: ByteRangeError) -> Self {
+        match err {
+            ByteRangeError::InvalidRange { start, end } => Self::InvalidRange { start, end },
+            ByteRangeError::ParseError(err) => Self::ParseError(err.into()),
+            ByteRangeError::InvalidFormat(at) => Self::InvalidFormatAt(at),
+        }
+    }
+}
+
+impl FuzzyDate {
+    /// Parses a date from raw bytes without allocating.
+    ///
+    /// Accepts exactly what `FromStr` accepts (ISO and US month-first), except
+    /// that only ASCII whitespace is trimmed. Errors are `Copy` and report byte
+    /// offsets rather than echoing the input.
+    ///
+    /// # Errors
+    /// Returns `ByteParseError` under the same conditions as the corresponding
+    /// `ParseError` from `FromStr`.
+    pub const fn parse_bytes(input: &[u8]) -> Result<Self, ByteParseError> {
+        let (start, end) = trim(input, 0, input.len());
+        parse_date(input, start, end)
+    }
+}
+
+impl FuzzyDateRange {
+    /// Parses a `start/end` range from raw bytes without allocating.
+    ///
+    /// Accepts exactly what `FromStr` accepts, except that only ASCII
+    /// whitespace is trimmed. Byte offsets in errors are relative to `input`.
+    ///
+    /// # Errors
+    /// Returns `ByteRangeError` under the same conditions as the corresponding
+    /// `RangeError` from `FromStr`.
+    pub const fn parse_bytes(input: &[u8]) -> Result<Self, ByteRangeError> {
+        let (start, end) = trim(input, 0, input.len());
+
+        let mut separator = None;
+        let mut pos = start;
+        while pos < end {
+            if input[pos] == b'/' {
+                if separator.is_some() {
+                    return Err(ByteRangeError::InvalidFormat(pos));
+                }
+                separator = Some(pos);
+            }
+            pos += 1;
+        }
+        let Some(separator) = separator else {
+            return Err(ByteRangeError::InvalidFormat(end));
+        };
+
+        let (start_lo, start_hi) = trim(input, start, separator);
+        let (end_lo, end_hi) = trim(input, separator + 1, end);
+        let start = match parse_date(input, start_lo, start_hi) {
+            Ok(date) => date,
+            Err(err) => return Err(ByteRangeError::ParseError(err)),
+        };
+        let end = match parse_date(input, end_lo, end_hi) {
+            Ok(date) => date,
+            Err(err) => return Err(ByteRangeError::ParseError(err)),
+        };
+        match range::ordered(start, end) {
+            Some(range) => Ok(range),
+            None => Err(ByteRangeError::InvalidRange { start, end }),
+        }
+    }
+}
+
+/// Narrows `start..end` to exclude leading and trailing ASCII whitespace.
+const fn trim(b: &[u8], mut start: usize, mut end: usize) -> (usize, usize) {
+    while start < end && b[start].is_ascii_whitespace() {
+        start += 1;
+    }
+    while end > start && b[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+    (start, end)
+}
+
+/// Parses the date in `b[start..end]`, which has already been trimmed.
+const fn parse_date(b: &[u8], start: usize, end: usize) -> Result<FuzzyDate, ByteParseError> {
+    if start == end {
+        return Err(ByteParseError::EmptyInput);
+    }
+
+    let mut hyphen = None;
+    let mut slash = None;
+    let mut pos = start;
+    while pos < end {
+        match b[pos] {
+            b'-' if hyphen.is_none() => hyphen = Some(pos),
+            b'/' if slash.is_none() => slash = Some(pos),
+            _ => {}
+        }
+        pos += 1;
+    }
+
+    match (hyphen, slash) {
+        // Mixed delimiters: point at whichever came second
+        (Some(h), Some(s)) => Err(ByteParseError::InvalidFormat(if h > s { h } else { s })),
+        (_, Some(_)) => parse_slash(b, start, end),
+        _ => parse_iso(b, start, end),
+    }
+}
+
+/// Parses `YYYY`, `YYYY-MM` or `YYYY-MM-DD`, allowing whitespace around each
+/// field and an optional `+` sign, as `FromStr` does.
+const fn parse_iso(b: &[u8], start: usize, end: usize) -> Result<FuzzyDate, ByteParseError> {
+    let mut fields = [(0, 0); 3];
+    let mut count = 0;
+    let mut field_start = start;
+    let mut pos = start;
+    while pos <= end {
+        if pos == end || b[pos] == b'-' {
+            if count == fields.len() {
+                return Err(ByteParseError::InvalidFormat(field_start - 1));
+            }
+            fields[count] = trim(b, field_start, pos);
+            count += 1;
+            field_start = pos + 1;
+        }
+        pos += 1;
+    }
+
+    let year = tri!(number(b, fields[0], u16::MAX as u32));
+    match count {
+        1 => Ok(FuzzyDate::Year {
+            year: tri!(year_from(year)),
+        }),
+        2 => {
+            let month = tri!(number(b, fields[1], u8::MAX as u32));
+            Ok(FuzzyDate::Month {
+                year: tri!(year_from(year)),
+                month: tri!(month_from(month)),
+            })
+        }
+        _ => {
+            let month = tri!(number(b, fields[1], u8::MAX as u32));
+            let day = tri!(number(b, fields[2], u8::MAX as u32));
+            Ok(FuzzyDate::Day {
+                year: tri!(year_from(year)),
+                month: tri!(month_from(month)),
+                day: tri!(day_from(year, month, day)),
+            })
+        }
+    }
+}
+
+/// Parses `MM/YYYY` or `MM/DD/YYYY` with the same strict field widths as `FromStr`.
+const fn parse_slash(b: &[u8], start: usize, end: usize) -> Result<FuzzyDate, ByteParseError> {
+    let mut fields = [(0, 0); 3];
+    let mut count = 0;
+    let mut field_start = start;
+    let mut pos = start;
+    while pos <= end {
+        if pos == end || b[pos] == b'/' {
+            if count == fields.len() {
+                return Err(ByteParseError::InvalidFormat(field_start - 1));
+            }
+            fields[count] = (field_start, pos);
+            count += 1;
+            field_start = pos + 1;
+        } else if !b[pos].is_ascii_digit() {
+            return Err(ByteParseError::InvalidFormat(pos));
+        }
+        pos += 1;
+    }
+
+    // Only called when a '/' is present, so there are at least two fields.
+    // Every field needs at least one digit; month and day at most two
+    let mut i = 0;
+    while i < count {
+        let (lo, hi) = fields[i];
+        let is_year = i == count - 1;
+        if lo == hi || (!is_year && hi - lo > 2) {
+            return Err(ByteParseError::InvalidFormat(lo));
+        }
+        i += 1;
+    }
+    if count == 2 {
+        let month = tri!(number(b, fields[0], u8::MAX as u32));
+        let year = tri!(number(b, fields[1], u16::MAX as u32));
+        let month = tri!(month_from(month));
+        return Ok(FuzzyDate::Month {
+            year: tri!(year_from(year)),
+            month,
+        });
+    }
+
+    let month = tri!(number(b, fields[0], u8::MAX as u32));
+    let day = tri!(number(b, fields[1], u8::MAX as u32));
+    let year = tri!(number(b, fields[2], u16::MAX as u32));
+    Ok(FuzzyDate::Day {
+        year: tri!(year_from(year)),
+        month: tri!(month_from(month)),
+        day: tri!(day_from(year, month, day)),
+    })
+}
+
+/// Reads an unsigned decimal field with an optional leading `+`, failing if it
+/// is empty, contains a non-digit, or exceeds `max`.
+const fn number(b: &[u8], (start, end): (usize, usize), max: u32) -> Result<u32, ByteParseError> {
+    let mut pos = start;
+    if pos < end && b[pos] == b'+' {
+        pos += 1;
+    }
+    if pos == end {
+        return Err(ByteParseError::InvalidFormat(start));
+    }
+
+    let mut value: u32 = 0;
+    while pos < end {
+        let digit = b[pos];
+        if !digit.is_ascii_digit() {
+            return Err(ByteParseError::InvalidFormat(pos));
+        }
+        value = value * 10 + (digit - b'0') as u32;
+        if value > max {
+            return Err(ByteParseError::InvalidFormat(start));
+        }
+        pos += 1;
+    }
+    Ok(value)
+}
+
+// `number` has already bounded each value by its target width, so the
+// narrowing casts below are lossless.
+
+#[allow(clippy::cast_possible_truncation)]
+pub const fn year_from(value: u32) -> Result<Year, ByteParseError> {
+    match types::checked_year(value as u16) {
+        Some(year) => Ok(year),
+        None => Err(ByteParseError::InvalidYear(value as u16)),
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub const fn month_from(value: u32) -> Result<Month, ByteParseError> {
+    match types::checked_month(value as u8) {
+        Some(month) => Ok(month),
+        None => Err(ByteParseError::InvalidMonth(value as u8)),
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub const fn day_from(year: u32, month: u32, day: u32) -> Result<Day, ByteParseError> {
+    let (year, month, day) = (year as u16, month as u8, day as u8);
+    match types::checked_day(day, year, month) {
+        Some(day) => Ok(day),
+        None => Err(ByteParseError::InvalidDay { month, day, year }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{fuzzy_day, fuzzy_month, fuzzy_year};
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_agrees_with_from_str() {
+        for input in crate::test_cases::PARSE_CASES {
+            let expected = input.parse::<FuzzyDate>();
+            let actual = FuzzyDate::parse_bytes(input.as_bytes());
+            match (expected, actual) {
+                (Ok(expected), Ok(actual)) => assert_eq!(expected, actual, "input: {input:?}"),
+                (Err(ParseError::InvalidFormat(_)), Err(ByteParseError::InvalidFormat(_))) => {}
+                (Err(expected), Err(actual)) => {
+                    assert_eq!(expected, ParseError::from(actual), "input: {input:?}");
+                }
+                (expected, actual) => {
+                    panic!(
+                        "input {input:?}: from_str gave {expected:?}, parse_bytes gave {actual:?}"
+                    )
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_bytes() {
+        assert_eq!(FuzzyDate::parse_bytes(b"2026"), Ok(fuzzy_year(2026)));
+        assert_eq!(FuzzyDate::parse_bytes(b"2026-02"), Ok(fuzzy_month(2026, 2)));
+        assert_eq!(
+            FuzzyDate::parse_bytes(b"02/13/2026"),
+            Ok(fuzzy_day(2026, 2, 13))
+        );
+    }
+
+    #[test]
+    fn test_error_offsets() {
+        assert_eq!(
+            FuzzyDate::parse_bytes(b"1991-08-XX"),
+            Err(ByteParseError::InvalidFormat(8))
+        );
+        assert_eq!(
+            FuzzyDate::parse_bytes(b"1991-08/15"),
+            Err(ByteParseError::InvalidFormat(7))
+        );
+        assert_eq!(
+            FuzzyDate::parse_bytes(b"2000-01-15-23"),
+            Err(ByteParseError::InvalidFormat(10))
+        );
+        assert_eq!(
+            FuzzyDate::parse_bytes(b"08/1991\xff"),
+            Err(ByteParseError::InvalidFormat(7))
+        );
+    }
+
+    #[test]
+    fn test_range_parse_bytes() {
+        let range = FuzzyDateRange::parse_bytes(b" 1990-01-15 / 2025-12 ")
+            .expect("expected range to parse");
+        assert_eq!(range.start(), fuzzy_day(1990, 1, 15));
+        assert_eq!(range.end(), fuzzy_month(2025, 12));
+
+        assert_eq!(
+            FuzzyDateRange::parse_bytes(b"19902000"),
+            Err(ByteRangeError::InvalidFormat(8))
+        );
+        assert_eq!(
+            FuzzyDateRange::parse_bytes(b"2000/2001/2002"),
+            Err(ByteRangeError::InvalidFormat(9))
+        );
+        assert_eq!(
+            FuzzyDateRange::parse_bytes(b"2000/1990"),
+            Err(ByteRangeError::InvalidRange {
+                start: fuzzy_year(2000),
+                end: fuzzy_year(1990),
+            })
+        );
+        // Offsets are relative to the whole range input
+        assert_eq!(
+            FuzzyDateRange::parse_bytes(b"1990/2000-XX"),
+            Err(ByteRangeError::ParseError(ByteParseError::InvalidFormat(
+                10
+            )))
+        );
+    }
+
+    #[test]
+    fn test_range_agrees_with_from_str() {
+        for input in [
+            "1990/2000",
+            "1990-01/2000-12-31",
+            "2000/1990",
+            "1990",
+            "1/2/3",
+            "/2000",
+        ] {
+            let expected = input.parse::<FuzzyDateRange>();
+            let actual = FuzzyDateRange::parse_bytes(input.as_bytes());
+            assert_eq!(expected.is_ok(), actual.is_ok(), "input: {input:?}");
+            if let (Ok(expected), Ok(actual)) = (expected, actual) {
+                assert_eq!(expected, actual);
+            }
+        }
+    }
+
+    #[test]
+    fn test_conversion_to_owned_errors() {
+        assert_eq!(
+            ParseError::from(ByteParseError::InvalidMonth(13)),
+            ParseError::InvalidMonth(13)
+        );
+        assert!(matches!(
+            RangeError::from(ByteRangeError::ParseError(ByteParseError::EmptyInput)),
+            RangeError::ParseError(ParseError::EmptyInput)
+        ));
+    }
+}
@@ -0,0 +1,240 @@
+//! Conversions between fuzzy dates and the date types of other crates.
+//!
+//! Each supported type implements [`CalendarDate`] behind its cargo feature,
+//! which provides the generic bound and range helpers below along with the
+//! usual `TryFrom` conversions.
+
+use core::ops::RangeInclusive;
+
+use crate::{FuzzyDate, FuzzyDateRange, MAX_YEAR, Precision, RangeError};
+
+pub mod sealed {
+    pub trait Sealed {}
+}
+
+/// A concrete calendar date from another crate that fuzzy dates convert to
+/// and from.
+///
+/// This trait is sealed; it is implemented for `chrono::NaiveDate`,
+/// `time::Date` and `jiff::civil::Date` when the feature of the same name is
+/// enabled.
+pub trait CalendarDate: sealed::Sealed + Copy {
+    /// Builds the date from components that are known to be valid.
+    #[doc(hidden)]
+    fn from_ymd(year: u16, month: u8, day: u8) -> Self;
+
+    /// Splits the date into its proleptic Gregorian components.
+    #[doc(hidden)]
+    fn to_ymd(self) -> (i32, u8, u8);
+}
+
+/// Error converting between fuzzy dates and other date types.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ConversionError {
+    /// Only day-precision dates name a single calendar date.
+    #[error("{0} does not have day precision")]
+    Imprecise(FuzzyDate),
+
+    /// The year is outside `1..=MAX_YEAR`.
+    #[error("Year {0} is outside the supported range 1..={MAX_YEAR}")]
+    YearOutOfRange(i32),
+
+    /// The converted range is invalid.
+    #[error(transparent)]
+    Range(#[from] RangeError),
+}
+
+impl FuzzyDate {
+    /// Earliest calendar date represented by this value.
+    pub fn lower_bound_date<D: CalendarDate>(&self) -> D {
+        let (year, month, day) = self.lower_bound();
+        D::from_ymd(year, month, day)
+    }
+
+    /// Latest calendar date represented by this value (inclusive).
+    pub fn upper_bound_date<D: CalendarDate>(&self) -> D {
+        let (year, month, day) = self.upper_bound_inclusive();
+        D::from_ymd(year, month, day)
+    }
+
+    /// Builds a fuzzy date from `date`, keeping only the components down to
+    /// `precision`.
+    ///
+    /// # Errors
+    /// Returns `ConversionError::YearOutOfRange` if the year of `date` is
+    /// outside `1..=MAX_YEAR`.
+    pub fn from_date<D: CalendarDate>(
+        date: D,
+        precision: Precision,
+    ) -> Result<Self, ConversionError> {
+        let (year, month, day) = date.to_ymd();
+        let year = u16::try_from(year)
+            .ok()
+            .filter(|year| (1..=MAX_YEAR).contains(year))
+            .ok_or(ConversionError::YearOutOfRange(year))?;
+        // Components of a valid date in the supported year range are valid
+        Self::from_columns(year, Some(month), Some(day))
+            .map(|date| date.truncate(precision))
+            .map_err(|_| ConversionError::YearOutOfRange(year.into()))
+    }
+
+    /// Converts a day-precision value to a calendar date.
+    pub(crate) fn to_date<D: CalendarDate>(self) -> Result<D, ConversionError> {
+        match self {
+            Self::Day { year, month, day } => Ok(D::from_ymd(year.get(), month.get(), day.get())),
+            _ => Err(ConversionError::Imprecise(self)),
+        }
+    }
+}
+
+impl FuzzyDateRange {
+    /// Earliest calendar date in the range.
+    pub fn lower_bound_date<D: CalendarDate>(&self) -> D {
+        self.start().lower_bound_date()
+    }
+
+    /// Latest calendar date in the range (inclusive).
+    pub fn upper_bound_date<D: CalendarDate>(&self) -> D {
+        self.end().upper_bound_date()
+    }
+}
+
+/// Every calendar date the range covers, from the start's lower bound to the
+/// end's upper bound.
+impl<D: CalendarDate> From<FuzzyDateRange> for RangeInclusive<D> {
+    fn from(range: FuzzyDateRange) -> Self {
+        range.lower_bound_date()..=range.upper_bound_date()
+    }
+}
+
+/// A range of two day-precision dates.
+impl<D: CalendarDate> TryFrom<RangeInclusive<D>> for FuzzyDateRange {
+    type Error = ConversionError;
+
+    fn try_from(range: RangeInclusive<D>) -> Result<Self, Self::Error> {
+        let (start, end) = range.into_inner();
+        Ok(Self::new(
+            FuzzyDate::from_date(start, Precision::Day)?,
+            FuzzyDate::from_date(end, Precision::Day)?,
+        )?)
+    }
+}
+
+/// Implements [`CalendarDate`]'s sealing and the `TryFrom` conversions with
+/// [`FuzzyDate`] for a date type.
+macro_rules! impl_calendar_date {
+    ($date:ty) => {
+        impl $crate::calendar::sealed::Sealed for $date {}
+
+        impl TryFrom<$crate::FuzzyDate> for $date {
+            type Error = $crate::ConversionError;
+
+            fn try_from(date: $crate::FuzzyDate) -> Result<Self, Self::Error> {
+                date.to_date()
+            }
+        }
+
+        /// A day-precision date.
+        impl TryFrom<$date> for $crate::FuzzyDate {
+            type Error = $crate::ConversionError;
+
+            fn try_from(date: $date) -> Result<Self, Self::Error> {
+                Self::from_date(date, $crate::Precision::Day)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_calendar_date;
+
+/// Checks shared by every [`CalendarDate`] implementation.
+#[cfg(test)]
+pub mod test_utils {
+    use core::{fmt::Debug, ops::RangeInclusive};
+
+    use super::CalendarDate;
+    use crate::{
+        ConversionError, FuzzyDate, FuzzyDateRange, Precision, RangeError,
+        test_utils::{fuzzy_day, fuzzy_month, fuzzy_year},
+        types::is_leap_year,
+    };
+
+    /// Runs the conversion checks against `D`; `out_of_range` is a date of
+    /// that type whose year fuzzy dates cannot represent.
+    pub fn check_calendar_date<D>(out_of_range: D)
+    where
+        D: CalendarDate + Debug + PartialEq + TryFrom<FuzzyDate, Error = ConversionError>,
+        FuzzyDate: TryFrom<D, Error = ConversionError>,
+    {
+        let date = |year, month, day| D::from_ymd(year, month, day);
+
+        // Bounds
+        let fuzzy = fuzzy_month(2024, 2);
+        assert_eq!(fuzzy.lower_bound_date::<D>(), date(2024, 2, 1));
+        assert_eq!(fuzzy.upper_bound_date::<D>(), date(2024, 2, 29));
+        let fuzzy = fuzzy_year(9999);
+        assert_eq!(fuzzy.lower_bound_date::<D>(), date(9999, 1, 1));
+        assert_eq!(fuzzy.upper_bound_date::<D>(), date(9999, 12, 31));
+
+        for year in 1..=9999 {
+            let (_, _, day) = fuzzy_month(year, 2).upper_bound_date::<D>().to_ymd();
+            assert_eq!(day == 29, is_leap_year(year), "year {year}");
+        }
+        for year in (1..=9999).step_by(7) {
+            for month in 1..=12 {
+                let fuzzy = fuzzy_month(year, month);
+                let (y, m, d) = fuzzy.upper_bound_inclusive();
+                assert_eq!(fuzzy.upper_bound_date::<D>(), date(y, m, d));
+            }
+        }
+
+        // Conversions
+        assert_eq!(D::try_from(fuzzy_day(2024, 2, 29)), Ok(date(2024, 2, 29)));
+        assert_eq!(
+            D::try_from(fuzzy_month(2024, 2)),
+            Err(ConversionError::Imprecise(fuzzy_month(2024, 2)))
+        );
+        assert_eq!(
+            FuzzyDate::try_from(date(2024, 2, 29)),
+            Ok(fuzzy_day(2024, 2, 29))
+        );
+        assert_eq!(
+            FuzzyDate::try_from(out_of_range),
+            Err(ConversionError::YearOutOfRange(out_of_range.to_ymd().0))
+        );
+        let day = date(1991, 8, 15);
+        assert_eq!(
+            FuzzyDate::from_date(day, Precision::Day),
+            Ok(fuzzy_day(1991, 8, 15))
+        );
+        assert_eq!(
+            FuzzyDate::from_date(day, Precision::Month),
+            Ok(fuzzy_month(1991, 8))
+        );
+        assert_eq!(
+            FuzzyDate::from_date(day, Precision::Year),
+            Ok(fuzzy_year(1991))
+        );
+
+        // Ranges
+        let range = FuzzyDateRange::new(fuzzy_month(2020, 3), fuzzy_year(2026))
+            .expect("expected valid test range");
+        let dates: RangeInclusive<D> = range.into();
+        assert_eq!(dates, date(2020, 3, 1)..=date(2026, 12, 31));
+        assert_eq!(range.lower_bound_date::<D>(), date(2020, 3, 1));
+        assert_eq!(range.upper_bound_date::<D>(), date(2026, 12, 31));
+
+        let back = FuzzyDateRange::try_from(dates).expect("expected valid range");
+        assert_eq!(
+            back.dates(),
+            (fuzzy_day(2020, 3, 1), fuzzy_day(2026, 12, 31))
+        );
+        assert_eq!(
+            FuzzyDateRange::try_from(date(2026, 1, 2)..=date(2026, 1, 1)),
+            Err(ConversionError::Range(RangeError::InvalidRange {
+                start: fuzzy_day(2026, 1, 2),
+                end: fuzzy_day(2026, 1, 1),
+            }))
+        );
+    }
+}
@@ -0,0 +1,32 @@
+//! `chrono` integration.
+
+use ::chrono::{Datelike, NaiveDate};
+
+use crate::calendar::{CalendarDate, impl_calendar_date};
+
+impl_calendar_date!(NaiveDate);
+
+impl CalendarDate for NaiveDate {
+    fn from_ymd(year: u16, month: u8, day: u8) -> Self {
+        Self::from_ymd_opt(year.into(), month.into(), day.into())
+            .unwrap_or_else(|| unreachable!("fuzzy date components are always a valid NaiveDate"))
+    }
+
+    #[allow(clippy::cast_possible_truncation)] // month and day fit in u8
+    fn to_ymd(self) -> (i32, u8, u8) {
+        (self.year(), self.month() as u8, self.day() as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::test_utils::check_calendar_date;
+
+    #[test]
+    fn test_calendar_date() {
+        let before_year_one =
+            NaiveDate::from_ymd_opt(-44, 3, 15).expect("expected valid test date");
+        check_calendar_date(before_year_one);
+    }
+}
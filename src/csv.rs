@@ -0,0 +1,590 @@
+//! `csv` integration.
+//!
+//! [`CsvNormalizer`] streams a CSV file, parses one column of raw date
+//! strings, and writes every row back out with the normalized ISO value and
+//! the year, month and day columns of `to_columns` appended. Rows that fail to
+//! parse are kept with the new cells empty and reported one at a time as
+//! [`CsvRowError`]s; the returned [`CsvSummary`] tallies precisions and
+//! failure kinds.
+
+use std::{
+    fmt,
+    io::{Read, Write},
+};
+
+use ::csv::{ReaderBuilder, StringRecord, WriterBuilder};
+
+use crate::{FuzzyDate, ParseError, Parser, Precision};
+
+/// The input column that holds the raw dates.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CsvColumn {
+    /// A column named in the header row
+    Name(String),
+    /// A zero-based column position
+    Index(usize),
+}
+
+impl From<&str> for CsvColumn {
+    fn from(name: &str) -> Self {
+        Self::Name(name.to_owned())
+    }
+}
+
+impl From<String> for CsvColumn {
+    fn from(name: String) -> Self {
+        Self::Name(name)
+    }
+}
+
+impl From<usize> for CsvColumn {
+    fn from(index: usize) -> Self {
+        Self::Index(index)
+    }
+}
+
+impl fmt::Display for CsvColumn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Name(name) => write!(f, "{name:?}"),
+            Self::Index(index) => write!(f, "{index}"),
+        }
+    }
+}
+
+/// Error that stops a [`CsvNormalizer`] run. Unparseable dates do not stop a
+/// run; they are reported as [`CsvRowError`]s.
+#[derive(Debug, thiserror::Error)]
+pub enum CsvError {
+    /// Reading or writing the CSV failed.
+    #[error(transparent)]
+    Csv(#[from] ::csv::Error),
+
+    /// The date column is not in the header row, or it was given by name
+    /// for input without headers.
+    #[error("No column {0} in the CSV header")]
+    MissingColumn(CsvColumn),
+}
+
+/// Broad reason a value failed to parse, for tallying failures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FailureKind {
+    /// The cell was blank or the row too short to have the column
+    Empty,
+    /// The value did not match any accepted layout
+    Format,
+    /// The year was out of range
+    Year,
+    /// The month was out of range
+    Month,
+    /// The day does not exist in its month
+    Day,
+    /// A two-digit year was rejected by the configured `Parser`
+    TwoDigitYear,
+}
+
+impl FailureKind {
+    /// Every kind, in the order [`CsvSummary`] reports them.
+    pub const ALL: [Self; 6] = [
+        Self::Empty,
+        Self::Format,
+        Self::Year,
+        Self::Month,
+        Self::Day,
+        Self::TwoDigitYear,
+    ];
+
+    /// The kind of failure `error` describes.
+    pub const fn of(error: &ParseError) -> Self {
+        match error {
+            ParseError::EmptyInput => Self::Empty,
+            ParseError::InvalidFormat(_)
+            | ParseError::InvalidFormatAt(_)
+            | ParseError::DayWithoutMonth { .. } => Self::Format,
+            ParseError::InvalidYear(_) => Self::Year,
+            ParseError::InvalidMonth(_) => Self::Month,
+            ParseError::InvalidDay { .. } => Self::Day,
+            ParseError::TwoDigitYear(_) => Self::TwoDigitYear,
+        }
+    }
+
+    const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for FailureKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "empty",
+            Self::Format => "invalid format",
+            Self::Year => "invalid year",
+            Self::Month => "invalid month",
+            Self::Day => "invalid day",
+            Self::TwoDigitYear => "two-digit year",
+        })
+    }
+}
+
+/// A row whose date failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvRowError {
+    line: u64,
+    value: String,
+    error: ParseError,
+}
+
+impl CsvRowError {
+    /// One-based line of the input on which the row starts.
+    pub const fn line(&self) -> u64 {
+        self.line
+    }
+
+    /// The raw cell.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Why the cell failed to parse.
+    pub const fn error(&self) -> &ParseError {
+        &self.error
+    }
+
+    /// The broad kind of failure.
+    pub const fn kind(&self) -> FailureKind {
+        FailureKind::of(&self.error)
+    }
+}
+
+impl fmt::Display for CsvRowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {:?}: {}", self.line, self.value, self.error)
+    }
+}
+
+impl std::error::Error for CsvRowError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Totals from a [`CsvNormalizer`] run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CsvSummary {
+    rows: usize,
+    blanks: usize,
+    precisions: [usize; 3],
+    failures: [usize; FailureKind::ALL.len()],
+}
+
+impl CsvSummary {
+    /// Number of data rows read, excluding the header row.
+    pub const fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Number of rows whose date parsed.
+    pub const fn parsed(&self) -> usize {
+        self.precisions[0] + self.precisions[1] + self.precisions[2]
+    }
+
+    /// Number of blank cells passed through when blanks are allowed.
+    pub const fn blanks(&self) -> usize {
+        self.blanks
+    }
+
+    /// Number of rows reported as errors.
+    pub fn failed(&self) -> usize {
+        self.failures.iter().sum()
+    }
+
+    /// Number of parsed dates recorded at `precision`.
+    pub const fn with_precision(&self, precision: Precision) -> usize {
+        self.precisions[precision as usize]
+    }
+
+    /// Number of rows that failed with `kind`.
+    pub const fn failures(&self, kind: FailureKind) -> usize {
+        self.failures[kind.index()]
+    }
+
+    const fn record(&mut self, result: Result<Precision, FailureKind>) {
+        match result {
+            Ok(precision) => self.precisions[precision as usize] += 1,
+            Err(kind) => self.failures[kind.index()] += 1,
+        }
+    }
+}
+
+/// `4 rows: 3 parsed (1 year, 1 month, 1 day), 1 failed (1 invalid month)`
+impl fmt::Display for CsvSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} rows: {} parsed", self.rows, self.parsed())?;
+        let precisions = [Precision::Year, Precision::Month, Precision::Day]
+            .map(|precision| (self.with_precision(precision), precision));
+        write_counts(f, &precisions)?;
+        if self.blanks > 0 {
+            write!(f, ", {} blank", self.blanks)?;
+        }
+        write!(f, ", {} failed", self.failed())?;
+        write_counts(f, &FailureKind::ALL.map(|kind| (self.failures(kind), kind)))
+    }
+}
+
+/// Writes ` (2 year, 1 day)` for the non-zero counts, or nothing.
+fn write_counts<T: fmt::Display>(f: &mut fmt::Formatter<'_>, counts: &[(usize, T)]) -> fmt::Result {
+    let mut separator = " (";
+    for (count, label) in counts.iter().filter(|(count, _)| *count > 0) {
+        write!(f, "{separator}{count} {label}")?;
+        separator = ", ";
+    }
+    if separator == ", " {
+        f.write_str(")")?;
+    }
+    Ok(())
+}
+
+/// Normalizes a CSV column of raw date strings.
+///
+/// Values are parsed with `FromStr` unless a [`Parser`] is set. Each output
+/// row is the input row followed by four cells: the ISO value, the year, the
+/// month and the day, with missing components left empty. With headers, the
+/// new columns are named after the date column with `_iso`, `_year`, `_month`
+/// and `_day` suffixes.
+///
+/// ```
+/// use fuzzy_date::{CsvNormalizer, FailureKind, Precision};
+///
+/// let input = "id,born\n1,08/1991\n2,1991-13\n";
+/// let mut output = Vec::new();
+/// let mut errors = Vec::new();
+/// let summary = CsvNormalizer::new("born").run(input.as_bytes(), &mut output, |err| {
+///     errors.push(err.to_string());
+/// })?;
+///
+/// assert_eq!(
+///     String::from_utf8(output)?,
+///     "id,born,born_iso,born_year,born_month,born_day\n\
+///      1,08/1991,1991-08,1991,8,\n\
+///      2,1991-13,,,,\n"
+/// );
+/// assert_eq!(errors, ["line 3: \"1991-13\": Invalid month: 13 (must be 1-12)"]);
+/// assert_eq!(summary.with_precision(Precision::Month), 1);
+/// assert_eq!(summary.failures(FailureKind::Month), 1);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvNormalizer {
+    column: CsvColumn,
+    headers: bool,
+    delimiter: u8,
+    allow_blanks: bool,
+    parser: Option<Parser>,
+}
+
+impl CsvNormalizer {
+    /// Creates a normalizer for the given column of comma-separated input
+    /// with a header row.
+    pub fn new(column: impl Into<CsvColumn>) -> Self {
+        Self {
+            column: column.into(),
+            headers: true,
+            delimiter: b',',
+            allow_blanks: false,
+            parser: None,
+        }
+    }
+
+    /// Sets whether the first row holds column names. Defaults to `true`.
+    #[must_use]
+    pub const fn with_headers(mut self, headers: bool) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    /// Sets the field delimiter for input and output. Defaults to `,`.
+    #[must_use]
+    pub const fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Passes blank cells through as missing values instead of reporting
+    /// them as [`FailureKind::Empty`]. Defaults to `false`.
+    #[must_use]
+    pub const fn with_blanks_allowed(mut self, allow: bool) -> Self {
+        self.allow_blanks = allow;
+        self
+    }
+
+    /// Parses values with `parser` instead of `FromStr`.
+    #[must_use]
+    pub const fn with_parser(mut self, parser: Parser) -> Self {
+        self.parser = Some(parser);
+        self
+    }
+
+    /// Reads CSV from `reader`, writes the normalized CSV to `writer`, and
+    /// calls `report` for each row whose date fails to parse.
+    ///
+    /// Rows are processed one at a time, so memory use does not grow with
+    /// the input. Rows may have differing lengths; a row too short to have the
+    /// date column counts as blank. Short rows are padded with empty cells to
+    /// the width of the header row, or without headers to the widest row so
+    /// far, so the new cells stay in their columns.
+    ///
+    /// # Errors
+    /// Returns `CsvError::MissingColumn` if the column cannot be found, and
+    /// `CsvError::Csv` if reading or writing fails.
+    pub fn run<R, W, F>(&self, reader: R, writer: W, mut report: F) -> Result<CsvSummary, CsvError>
+    where
+        R: Read,
+        W: Write,
+        F: FnMut(CsvRowError),
+    {
+        let mut reader = ReaderBuilder::new()
+            .has_headers(self.headers)
+            .delimiter(self.delimiter)
+            .flexible(true)
+            .from_reader(reader);
+        let mut writer = WriterBuilder::new()
+            .delimiter(self.delimiter)
+            .flexible(true)
+            .from_writer(writer);
+
+        let mut width = 0;
+        let index = if self.headers {
+            let headers = reader.headers()?.clone();
+            width = headers.len();
+            let index = self.column_index(&headers)?;
+            let name = &headers[index];
+            let mut out = headers.clone();
+            for suffix in ["iso", "year", "month", "day"] {
+                out.push_field(&format!("{name}_{suffix}"));
+            }
+            writer.write_record(&out)?;
+            index
+        } else {
+            match self.column {
+                CsvColumn::Index(index) => index,
+                CsvColumn::Name(_) => return Err(CsvError::MissingColumn(self.column.clone())),
+            }
+        };
+
+        let mut summary = CsvSummary::default();
+        let mut record = StringRecord::new();
+        while reader.read_record(&mut record)? {
+            summary.rows += 1;
+            if !self.headers {
+                width = width.max(record.len());
+            }
+            let value = record.get(index).unwrap_or_default();
+            let parsed = if self.allow_blanks && value.trim().is_empty() {
+                summary.blanks += 1;
+                None
+            } else {
+                match self.parse(value) {
+                    Ok(date) => {
+                        summary.record(Ok(date.precision()));
+                        Some(date)
+                    }
+                    Err(error) => {
+                        summary.record(Err(FailureKind::of(&error)));
+                        report(CsvRowError {
+                            line: record.position().map_or(0, ::csv::Position::line),
+                            value: value.to_owned(),
+                            error,
+                        });
+                        None
+                    }
+                }
+            };
+            write_row(&mut writer, &record, width, parsed)?;
+        }
+        writer.flush().map_err(::csv::Error::from)?;
+        Ok(summary)
+    }
+
+    fn column_index(&self, headers: &StringRecord) -> Result<usize, CsvError> {
+        match &self.column {
+            CsvColumn::Name(name) => headers.iter().position(|header| header == name),
+            CsvColumn::Index(index) => Some(*index).filter(|&index| index < headers.len()),
+        }
+        .ok_or_else(|| CsvError::MissingColumn(self.column.clone()))
+    }
+
+    fn parse(&self, value: &str) -> Result<FuzzyDate, ParseError> {
+        self.parser
+            .as_ref()
+            .map_or_else(|| value.parse(), |parser| parser.parse(value))
+    }
+}
+
+fn write_row<W: Write>(
+    writer: &mut ::csv::Writer<W>,
+    record: &StringRecord,
+    width: usize,
+    date: Option<FuzzyDate>,
+) -> Result<(), ::csv::Error> {
+    let mut iso = String::new();
+    let (mut year, mut month, mut day) = (String::new(), String::new(), String::new());
+    if let Some(date) = date {
+        iso = date.to_string();
+        let (y, m, d) = date.to_columns();
+        year = y.to_string();
+        month = m.map(|m| m.to_string()).unwrap_or_default();
+        day = d.map(|d| d.to_string()).unwrap_or_default();
+    }
+    for field in record {
+        writer.write_field(field)?;
+    }
+    for _ in record.len()..width {
+        writer.write_field("")?;
+    }
+    for field in [iso, year, month, day] {
+        writer.write_field(field)?;
+    }
+    writer.write_record(None::<&[u8]>)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DateFormat, TwoDigitYears};
+
+    fn normalize(
+        normalizer: &CsvNormalizer,
+        input: &str,
+    ) -> (String, Vec<CsvRowError>, CsvSummary) {
+        let mut output = Vec::new();
+        let mut errors = Vec::new();
+        let summary = normalizer
+            .run(input.as_bytes(), &mut output, |err| errors.push(err))
+            .expect("expected the CSV to normalize");
+        (
+            String::from_utf8(output).expect("expected UTF-8 output"),
+            errors,
+            summary,
+        )
+    }
+
+    #[test]
+    fn test_normalizes_column() {
+        let input = "name,born,note\n\
+                     Ada,1815-12-10,x\n\
+                     Bo,\"08/1991\",\"a, b\"\n\
+                     Cy,2026,\n";
+        let (output, errors, summary) = normalize(&CsvNormalizer::new("born"), input);
+        assert_eq!(
+            output,
+            "name,born,note,born_iso,born_year,born_month,born_day\n\
+             Ada,1815-12-10,x,1815-12-10,1815,12,10\n\
+             Bo,08/1991,\"a, b\",1991-08,1991,8,\n\
+             Cy,2026,,2026,2026,,\n"
+        );
+        assert!(errors.is_empty());
+        assert_eq!(summary.rows(), 3);
+        assert_eq!(summary.parsed(), 3);
+        assert_eq!(summary.with_precision(Precision::Day), 1);
+        assert_eq!(
+            summary.to_string(),
+            "3 rows: 3 parsed (1 year, 1 month, 1 day), 0 failed"
+        );
+    }
+
+    #[test]
+    fn test_reports_errors() {
+        let input = "born\n1991-13\n2021-02-30\nnope\n\"multi\nline\"\n1991\n";
+        let (output, errors, summary) = normalize(&CsvNormalizer::new("born"), input);
+        let lines: Vec<_> = errors.iter().map(CsvRowError::line).collect();
+        assert_eq!(lines, [2, 3, 4, 5]);
+        assert_eq!(errors[0].kind(), FailureKind::Month);
+        assert_eq!(
+            errors[1].error(),
+            &ParseError::InvalidDay {
+                month: 2,
+                day: 30,
+                year: 2021
+            }
+        );
+        assert_eq!(errors[2].value(), "nope");
+        assert!(output.ends_with("1991,1991,1991,,\n"));
+        assert_eq!(
+            summary.to_string(),
+            "5 rows: 1 parsed (1 year), 4 failed (2 invalid format, 1 invalid month, 1 invalid day)"
+        );
+    }
+
+    #[test]
+    fn test_blank_cells() {
+        let input = "id,born\n1,\n2\n3, 2026 \n";
+        let (_, errors, summary) = normalize(&CsvNormalizer::new("born"), input);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(summary.failures(FailureKind::Empty), 2);
+
+        let allowed = CsvNormalizer::new("born").with_blanks_allowed(true);
+        let (output, errors, summary) = normalize(&allowed, input);
+        assert!(errors.is_empty());
+        assert_eq!(summary.blanks(), 2);
+        assert_eq!(output.lines().nth(2), Some("2,,,,,"));
+        assert_eq!(
+            summary.to_string(),
+            "3 rows: 1 parsed (1 year), 2 blank, 0 failed"
+        );
+    }
+
+    #[test]
+    fn test_pads_short_rows() {
+        let input = "id,born,note\n1,1991\n2\n3,2026,x\n";
+        let (output, errors, _) = normalize(&CsvNormalizer::new("born"), input);
+        assert_eq!(
+            output,
+            "id,born,note,born_iso,born_year,born_month,born_day\n\
+             1,1991,,1991,1991,,\n\
+             2,,,,,,\n\
+             3,2026,x,2026,2026,,\n"
+        );
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind(), FailureKind::Empty);
+
+        let normalizer = CsvNormalizer::new(1).with_headers(false);
+        let (output, _, _) = normalize(&normalizer, "a,2026,x\nb,1991\nc\n");
+        assert_eq!(
+            output,
+            "a,2026,x,2026,2026,,\n\
+             b,1991,,1991,1991,,\n\
+             c,,,,,,\n"
+        );
+    }
+
+    #[test]
+    fn test_options() {
+        let normalizer = CsvNormalizer::new(1)
+            .with_headers(false)
+            .with_delimiter(b';')
+            .with_parser(
+                Parser::new(DateFormat::DayFirst).with_two_digit_years(TwoDigitYears::Reject),
+            );
+        let (output, errors, summary) = normalize(&normalizer, "a;13/02/2026\nb;13/02/26\n");
+        assert_eq!(
+            output,
+            "a;13/02/2026;2026-02-13;2026;2;13\nb;13/02/26;;;;\n"
+        );
+        assert_eq!(errors[0].kind(), FailureKind::TwoDigitYear);
+        assert_eq!(summary.failures(FailureKind::TwoDigitYear), 1);
+    }
+
+    #[test]
+    fn test_missing_column() {
+        for normalizer in [
+            CsvNormalizer::new("died"),
+            CsvNormalizer::new(5),
+            CsvNormalizer::new("born").with_headers(false),
+        ] {
+            let err = normalizer
+                .run(&b"born\n2026\n"[..], std::io::sink(), |_| {})
+                .expect_err("expected a missing column");
+            assert!(matches!(err, CsvError::MissingColumn(_)), "{err}");
+        }
+    }
+}
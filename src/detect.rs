@@ -0,0 +1,182 @@
+use crate::{DateFormat, Parser, prelude::*};
+
+/// Tallies which [`DateFormat`]s can parse a column of sample values.
+///
+/// Feed values with [`observe`](Self::observe) (or build one in a single call
+/// with [`detect`](Self::detect)), then ask which formats were consistent with
+/// every sample and which [`Parser`] to use for the column. Blank values are
+/// treated as missing and skipped.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FormatDetector {
+    samples: usize,
+    blanks: usize,
+    counts: [usize; DateFormat::ALL.len()],
+}
+
+impl FormatDetector {
+    /// Creates an empty detector.
+    pub const fn new() -> Self {
+        Self {
+            samples: 0,
+            blanks: 0,
+            counts: [0; DateFormat::ALL.len()],
+        }
+    }
+
+    /// Runs detection over every value in `values`.
+    pub fn detect<I>(values: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut detector = Self::new();
+        for value in values {
+            detector.observe(value.as_ref());
+        }
+        detector
+    }
+
+    /// Tests a single value against every candidate format.
+    pub fn observe(&mut self, value: &str) {
+        if value.trim().is_empty() {
+            self.blanks += 1;
+            return;
+        }
+
+        self.samples += 1;
+        for format in DateFormat::ALL {
+            if Parser::new(format).parse(value).is_ok() {
+                self.counts[format.index()] += 1;
+            }
+        }
+    }
+
+    /// Number of non-blank values observed.
+    pub const fn samples(&self) -> usize {
+        self.samples
+    }
+
+    /// Number of blank values skipped.
+    pub const fn blanks(&self) -> usize {
+        self.blanks
+    }
+
+    /// Number of observed values that `format` parsed successfully.
+    pub const fn matches(&self, format: DateFormat) -> usize {
+        self.counts[format.index()]
+    }
+
+    /// Formats that parsed every observed value, in order of preference.
+    /// Empty if nothing has been observed.
+    pub fn consistent_formats(&self) -> Vec<DateFormat> {
+        if self.samples == 0 {
+            return Vec::new();
+        }
+        DateFormat::ALL
+            .into_iter()
+            .filter(|&format| self.matches(format) == self.samples)
+            .collect()
+    }
+
+    /// Returns `true` if more than one format is consistent with every value,
+    /// e.g. a column where every day is 12 or less reads as both month-first
+    /// and day-first.
+    pub fn is_ambiguous(&self) -> bool {
+        self.consistent_formats().len() > 1
+    }
+
+    /// The parser best suited to the observed values.
+    ///
+    /// Prefers the first consistent format in [`DateFormat::ALL`] order; if no
+    /// format parses every value, falls back to the one that parsed the most.
+    /// Returns `None` if no value was parsed by any format.
+    pub fn recommended_parser(&self) -> Option<Parser> {
+        if let Some(&format) = self.consistent_formats().first() {
+            return Some(Parser::new(format));
+        }
+
+        DateFormat::ALL
+            .into_iter()
+            .filter(|&format| self.matches(format) > 0)
+            // max_by_key returns the last maximum; reverse to keep preference order on ties
+            .rev()
+            .max_by_key(|&format| self.matches(format))
+            .map(Parser::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ambiguous_slash_column() {
+        let detector = FormatDetector::detect(["03/04/2021", "01/02/2020", "12/11/2019"]);
+        assert_eq!(detector.samples(), 3);
+        assert_eq!(
+            detector.consistent_formats(),
+            vec![DateFormat::MonthFirst, DateFormat::DayFirst]
+        );
+        assert!(detector.is_ambiguous());
+        assert_eq!(
+            detector.recommended_parser(),
+            Some(Parser::new(DateFormat::MonthFirst))
+        );
+    }
+
+    #[test]
+    fn test_day_first_disambiguated() {
+        let detector = FormatDetector::detect(["03/04/2021", "25/12/2020"]);
+        assert_eq!(detector.matches(DateFormat::MonthFirst), 1);
+        assert_eq!(detector.matches(DateFormat::DayFirst), 2);
+        assert_eq!(detector.consistent_formats(), vec![DateFormat::DayFirst]);
+        assert!(!detector.is_ambiguous());
+        assert_eq!(
+            detector.recommended_parser(),
+            Some(Parser::new(DateFormat::DayFirst))
+        );
+    }
+
+    #[test]
+    fn test_per_format_counts() {
+        let detector = FormatDetector::detect(["2021-03-04", "2021", "20210304", "4 Mar 2021"]);
+        assert_eq!(detector.matches(DateFormat::Iso), 2);
+        assert_eq!(detector.matches(DateFormat::Basic), 2);
+        assert_eq!(detector.matches(DateFormat::MonthName), 1);
+        assert_eq!(detector.matches(DateFormat::Dotted), 0);
+        assert!(detector.consistent_formats().is_empty());
+
+        // Tie between ISO and basic resolves to the preferred format
+        assert_eq!(
+            detector.recommended_parser(),
+            Some(Parser::new(DateFormat::Iso))
+        );
+    }
+
+    #[test]
+    fn test_blanks_are_skipped() {
+        let detector = FormatDetector::detect(["13.04.2021", "", "   ", "04.2021"]);
+        assert_eq!(detector.samples(), 2);
+        assert_eq!(detector.blanks(), 2);
+        assert_eq!(detector.consistent_formats(), vec![DateFormat::Dotted]);
+    }
+
+    #[test]
+    fn test_nothing_parses() {
+        let detector = FormatDetector::detect(["not a date", "n/a"]);
+        assert!(detector.consistent_formats().is_empty());
+        assert_eq!(detector.recommended_parser(), None);
+
+        let empty = FormatDetector::new();
+        assert!(empty.consistent_formats().is_empty());
+        assert_eq!(empty.recommended_parser(), None);
+    }
+
+    #[test]
+    fn test_observe_incrementally() {
+        let mut detector = FormatDetector::new();
+        detector.observe("Feb 2026");
+        detector.observe("February 13, 2026");
+        assert_eq!(detector.consistent_formats(), vec![DateFormat::MonthName]);
+    }
+}
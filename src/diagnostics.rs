@@ -0,0 +1,615 @@
+use core::{fmt, ops::Range};
+
+use crate::{
+    DATE_SEPARATOR, DateFormat, FuzzyDate, MONTH_FIRST_SEPARATOR, ParseError, Parser,
+    format::month_from_name, prelude::*, types::days_in_month,
+};
+
+/// The part of the input a [`Diagnostic`] points at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Component {
+    /// The year field
+    Year,
+    /// The month field
+    Month,
+    /// The day field
+    Day,
+    /// A separator between fields, or a missing one
+    Separator,
+    /// The input as a whole (empty, or an unrecognised shape)
+    Input,
+}
+
+/// A parse failure located within the original input.
+///
+/// Wraps the same [`ParseError`] that plain parsing returns, adding the byte
+/// span of the offending component, what was expected there, and a corrected
+/// date when one can be inferred.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    error: ParseError,
+    span: Range<usize>,
+    component: Component,
+    expected: &'static str,
+    suggestion: Option<FuzzyDate>,
+}
+
+impl Diagnostic {
+    /// The underlying parse error, identical to the one returned by plain parsing.
+    pub const fn error(&self) -> &ParseError {
+        &self.error
+    }
+
+    /// Byte range of the offending component within the original input.
+    /// Empty when something is missing, positioned where it was expected.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Which component failed.
+    pub const fn component(&self) -> Component {
+        self.component
+    }
+
+    /// Human-readable description of what was expected at [`span`](Self::span).
+    pub const fn expected(&self) -> &'static str {
+        self.expected
+    }
+
+    /// A valid date the input was probably meant to be, if one can be inferred.
+    pub const fn suggestion(&self) -> Option<FuzzyDate> {
+        self.suggestion
+    }
+
+    /// Discards the location details, returning the plain parse error.
+    pub fn into_error(self) -> ParseError {
+        self.error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at {}..{}: expected {}",
+            self.error, self.span.start, self.span.end, self.expected
+        )?;
+        if let Some(suggestion) = self.suggestion {
+            write!(f, " (did you mean {suggestion}?)")?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for Diagnostic {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<Diagnostic> for ParseError {
+    fn from(diagnostic: Diagnostic) -> Self {
+        diagnostic.error
+    }
+}
+
+impl FuzzyDate {
+    /// Parses like `FromStr`, but on failure reports where and why.
+    ///
+    /// # Errors
+    /// Returns a `Diagnostic` wrapping the same `ParseError` that
+    /// `s.parse::<FuzzyDate>()` would return.
+    pub fn diagnose(s: &str) -> Result<Self, Diagnostic> {
+        s.parse().map_err(|error| {
+            let trimmed = s.trim();
+            // Mixed delimiters are reported against whichever separator came first.
+            let format = match (
+                trimmed.find(DATE_SEPARATOR),
+                trimmed.find(MONTH_FIRST_SEPARATOR),
+            ) {
+                (Some(hyphen), Some(slash)) if hyphen < slash => DateFormat::Iso,
+                (_, Some(_)) => DateFormat::MonthFirst,
+                _ => DateFormat::Iso,
+            };
+            locate(format, s, error)
+        })
+    }
+}
+
+impl Parser {
+    /// Parses like [`Parser::parse`], but on failure reports where and why.
+    ///
+    /// # Errors
+    /// Returns a `Diagnostic` wrapping the same `ParseError` that
+    /// [`Parser::parse`] would return.
+    pub fn diagnose(&self, s: &str) -> Result<FuzzyDate, Diagnostic> {
+        self.parse(s)
+            .map_err(|error| locate(self.format(), s, error))
+    }
+}
+
+const EXPECTED_YEAR: &str = "a year between 1 and 9999";
+const EXPECTED_MONTH: &str = "a month between 1 and 12";
+const EXPECTED_DAY: &str = "a day that exists in the given month";
+const EXPECTED_MONTH_NAME: &str = "an English month name";
+
+/// A field of the input with its role and byte span (relative to the trimmed input).
+#[derive(Debug, Clone, Copy)]
+struct Field {
+    component: Component,
+    start: usize,
+    end: usize,
+}
+
+/// Builds a `Diagnostic` for `error`, which `format` produced while parsing `input`.
+fn locate(format: DateFormat, input: &str, error: ParseError) -> Diagnostic {
+    let trimmed = input.trim();
+    let offset = input.len() - input.trim_start().len();
+
+    if matches!(error, ParseError::EmptyInput) {
+        return Diagnostic {
+            error,
+            span: 0..input.len(),
+            component: Component::Input,
+            expected: "a date",
+            suggestion: None,
+        };
+    }
+
+    let (fields, problem) = split_fields(format, trimmed);
+    let (span, component, expected) = pinpoint(&error, &fields, problem)
+        .unwrap_or_else(|| (0..trimmed.len(), Component::Input, pattern(format)));
+    let suggestion = suggest(format, trimmed, &error, &fields);
+
+    Diagnostic {
+        error,
+        // Spans were computed against the trimmed input
+        span: span.start + offset..span.end + offset,
+        component,
+        expected,
+        suggestion,
+    }
+}
+
+/// Splits trimmed input into its fields according to `format`.
+fn split_fields(format: DateFormat, trimmed: &str) -> (Vec<Field>, Problem) {
+    match format {
+        DateFormat::Iso => separated_fields(trimmed, DATE_SEPARATOR, format),
+        DateFormat::MonthFirst | DateFormat::DayFirst => {
+            separated_fields(trimmed, MONTH_FIRST_SEPARATOR, format)
+        }
+        DateFormat::Dotted => separated_fields(trimmed, '.', format),
+        DateFormat::Basic => basic_fields(trimmed),
+        DateFormat::MonthName => month_name_fields(trimmed),
+    }
+}
+
+/// Text of the field playing `component` in trimmed input of the given format.
+pub fn field_text(format: DateFormat, trimmed: &str, component: Component) -> Option<&str> {
+    split_fields(format, trimmed)
+        .0
+        .into_iter()
+        .find(|field| field.component == component)
+        .map(|field| &trimmed[field.start..field.end])
+}
+
+/// A structural problem found while splitting the input: span, component, expectation.
+type Problem = Option<(Range<usize>, Component, &'static str)>;
+
+/// Picks the span to report: value errors point at their field, format errors
+/// at the first structural problem found.
+fn pinpoint(error: &ParseError, fields: &[Field], problem: Problem) -> Problem {
+    let (component, expected) = match error {
+        ParseError::InvalidYear(_) => (Component::Year, EXPECTED_YEAR),
+        ParseError::TwoDigitYear(_) => (Component::Year, "a four-digit year"),
+        ParseError::InvalidMonth(_) => (Component::Month, EXPECTED_MONTH),
+        ParseError::InvalidDay { .. } => (Component::Day, EXPECTED_DAY),
+        ParseError::InvalidFormat(_)
+        | ParseError::InvalidFormatAt(_)
+        | ParseError::DayWithoutMonth { .. }
+        | ParseError::EmptyInput => {
+            return problem;
+        }
+    };
+    fields
+        .iter()
+        .find(|field| field.component == component)
+        .map(|field| (field.start..field.end, component, expected))
+        .or(problem)
+}
+
+/// Roles of each field for a separated format with `count` fields.
+const fn roles(format: DateFormat, count: usize) -> &'static [Component] {
+    use Component::{Day, Month, Year};
+    match (format, count) {
+        (DateFormat::Iso, 1) => &[Year],
+        (DateFormat::Iso, 2) => &[Year, Month],
+        (DateFormat::Iso, _) => &[Year, Month, Day],
+        (_, 2) => &[Month, Year],
+        (DateFormat::MonthFirst, _) => &[Month, Day, Year],
+        _ => &[Day, Month, Year],
+    }
+}
+
+/// Splits a separated date into fields and finds the first structural problem.
+fn separated_fields(s: &str, sep: char, format: DateFormat) -> (Vec<Field>, Problem) {
+    let expected_sep = match sep {
+        DATE_SEPARATOR => "'-'",
+        MONTH_FIRST_SEPARATOR => "'/'",
+        _ => "'.'",
+    };
+
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        if c == sep {
+            pieces.push((start, i));
+            start = i + c.len_utf8();
+        }
+    }
+    pieces.push((start, s.len()));
+
+    if pieces.len() > 3 {
+        let extra = pieces[3].0 - sep.len_utf8();
+        let problem = (
+            extra..extra + sep.len_utf8(),
+            Component::Separator,
+            "no more than two separators",
+        );
+        return (Vec::new(), Some(problem));
+    }
+    if format != DateFormat::Iso && pieces.len() == 1 {
+        let problem = first_foreign(s, 0, s.len())
+            .map(|at| (at..at + 1, Component::Separator, expected_sep))
+            .unwrap_or((s.len()..s.len(), Component::Separator, expected_sep));
+        return (Vec::new(), Some(problem));
+    }
+
+    let fields: Vec<Field> = roles(format, pieces.len())
+        .iter()
+        .zip(&pieces)
+        .map(|(&component, &(start, end))| {
+            // ISO tolerates whitespace around each field
+            let (start, end) = if format == DateFormat::Iso {
+                let piece = &s[start..end];
+                let lead = piece.len() - piece.trim_start().len();
+                (start + lead, start + lead + piece.trim().len())
+            } else {
+                (start, end)
+            };
+            Field {
+                component,
+                start,
+                end,
+            }
+        })
+        .collect();
+
+    let problem = fields.iter().find_map(|field| {
+        let text = &s[field.start..field.end];
+        if let Some(at) = first_foreign(s, field.start, field.end) {
+            return Some((at..at + 1, Component::Separator, expected_sep));
+        }
+        let max_len = if field.component == Component::Year {
+            4
+        } else {
+            2
+        };
+        let well_formed = !text.is_empty()
+            && text.bytes().all(|b| b.is_ascii_digit())
+            && (text.len() <= max_len || format == DateFormat::Iso);
+        (!well_formed).then(|| {
+            let expected = match field.component {
+                Component::Year => "a numeric year",
+                Component::Month => "a 1-2 digit month",
+                _ => "a 1-2 digit day",
+            };
+            (field.start..field.end, field.component, expected)
+        })
+    });
+
+    (fields, problem)
+}
+
+/// Position of the first date separator character in `s[start..end]` that
+/// does not belong there.
+fn first_foreign(s: &str, start: usize, end: usize) -> Option<usize> {
+    s[start..end].find(['-', '/', '.']).map(|at| start + at)
+}
+
+/// Fixed field positions of ISO basic format.
+fn basic_fields(s: &str) -> (Vec<Field>, Problem) {
+    if let Some(at) = s.find(|c: char| !c.is_ascii_digit()) {
+        let len = s[at..].chars().next().map_or(1, char::len_utf8);
+        let problem = if first_foreign(s, at, at + len).is_some() {
+            (at..at + len, Component::Separator, "no separators")
+        } else {
+            (at..at + len, Component::Input, "only digits")
+        };
+        return (Vec::new(), Some(problem));
+    }
+
+    let field = |component, start, end| Field {
+        component,
+        start,
+        end,
+    };
+    match s.len() {
+        4 => (vec![field(Component::Year, 0, 4)], None),
+        8 => (
+            vec![
+                field(Component::Year, 0, 4),
+                field(Component::Month, 4, 6),
+                field(Component::Day, 6, 8),
+            ],
+            None,
+        ),
+        _ => (Vec::new(), None),
+    }
+}
+
+/// Token positions for month-name format.
+fn month_name_fields(s: &str) -> (Vec<Field>, Problem) {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (i, c) in s.char_indices().chain([(s.len(), ' ')]) {
+        let boundary = c.is_whitespace() || c == ',';
+        match (start, boundary) {
+            (None, false) => start = Some(i),
+            (Some(begin), true) => {
+                tokens.push((begin, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if tokens.len() < 2 || tokens.len() > 3 {
+        return (Vec::new(), None);
+    }
+
+    let is_name =
+        |&(start, end): &(usize, usize)| s[start..end].bytes().any(|b| b.is_ascii_alphabetic());
+    let year = tokens[tokens.len() - 1];
+    let mut fields = vec![Field {
+        component: Component::Year,
+        start: year.0,
+        end: year.1,
+    }];
+    let mut problem = None;
+    for &token in &tokens[..tokens.len() - 1] {
+        let component = if is_name(&token) {
+            Component::Month
+        } else {
+            Component::Day
+        };
+        if component == Component::Month && month_from_name(&s[token.0..token.1]).is_none() {
+            problem.get_or_insert((token.0..token.1, Component::Month, EXPECTED_MONTH_NAME));
+        }
+        fields.push(Field {
+            component,
+            start: token.0,
+            end: token.1,
+        });
+    }
+    if problem.is_none() && !fields.iter().any(|f| f.component == Component::Month) {
+        problem = Some((0..s.len(), Component::Month, EXPECTED_MONTH_NAME));
+    }
+    if problem.is_none() && s[year.0..year.1].bytes().any(|b| !b.is_ascii_digit()) {
+        problem = Some((year.0..year.1, Component::Year, "a numeric year"));
+    }
+
+    (fields, problem)
+}
+
+/// Canonical layout of each format, used when no narrower span can be found.
+const fn pattern(format: DateFormat) -> &'static str {
+    match format {
+        DateFormat::Iso => "YYYY, YYYY-MM or YYYY-MM-DD",
+        DateFormat::MonthFirst => "MM/YYYY or MM/DD/YYYY",
+        DateFormat::DayFirst => "MM/YYYY or DD/MM/YYYY",
+        DateFormat::Dotted => "MM.YYYY or DD.MM.YYYY",
+        DateFormat::Basic => "YYYY or YYYYMMDD",
+        DateFormat::MonthName => "Mon YYYY, D Mon YYYY or Mon D, YYYY",
+    }
+}
+
+/// Infers the date the input was probably meant to be.
+fn suggest(
+    format: DateFormat,
+    trimmed: &str,
+    error: &ParseError,
+    fields: &[Field],
+) -> Option<FuzzyDate> {
+    match *error {
+        // Day past the end of the month: clamp to the last day
+        ParseError::InvalidDay { year, month, day } if day > 0 && month > 0 => {
+            let last = days_in_month(year, month);
+            (day > last)
+                .then(|| FuzzyDate::from_columns(year, Some(month), Some(last)).ok())
+                .flatten()
+        }
+        // Month out of range but the day would be a valid month: swapped fields
+        ParseError::InvalidMonth(_) if fields.len() == 3 => {
+            let value = |component| {
+                fields
+                    .iter()
+                    .find(|f| f.component == component)
+                    .and_then(|f| trimmed[f.start..f.end].trim().parse::<u16>().ok())
+            };
+            let year = value(Component::Year)?;
+            let month = u8::try_from(value(Component::Day)?).ok()?;
+            let day = u8::try_from(value(Component::Month)?).ok()?;
+            FuzzyDate::from_columns(year, Some(month), Some(day)).ok()
+        }
+        // Wrong or mixed separators: normalize them and try again
+        ParseError::InvalidFormat(_) => {
+            let sep = match format {
+                DateFormat::Iso => DATE_SEPARATOR,
+                DateFormat::MonthFirst | DateFormat::DayFirst => MONTH_FIRST_SEPARATOR,
+                DateFormat::Dotted => '.',
+                DateFormat::Basic | DateFormat::MonthName => return None,
+            };
+            let normalized: String = trimmed
+                .chars()
+                .map(|c| if matches!(c, '-' | '/' | '.') { sep } else { c })
+                .collect();
+            (normalized != trimmed)
+                .then(|| Parser::new(format).parse(&normalized).ok())
+                .flatten()
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::fuzzy_day;
+
+    fn diagnose(input: &str) -> Diagnostic {
+        FuzzyDate::diagnose(input).expect_err("expected input to fail")
+    }
+
+    fn diagnose_as(format: DateFormat, input: &str) -> Diagnostic {
+        Parser::new(format)
+            .diagnose(input)
+            .expect_err("expected input to fail")
+    }
+
+    #[test]
+    fn test_valid_input_parses() {
+        assert_eq!(
+            FuzzyDate::diagnose("2021-02-28"),
+            Ok(fuzzy_day(2021, 2, 28))
+        );
+        assert_eq!(
+            Parser::new(DateFormat::DayFirst).diagnose("28/02/2021"),
+            Ok(fuzzy_day(2021, 2, 28))
+        );
+    }
+
+    #[test]
+    fn test_error_matches_from_str() {
+        for input in ["", "2021-13", "2021-02-30", "1991-08/15", "199A", "10000"] {
+            let expected = input
+                .parse::<FuzzyDate>()
+                .expect_err("expected input to fail");
+            assert_eq!(diagnose(input).into_error(), expected, "input: {input:?}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_day_suggests_month_end() {
+        let diagnostic = diagnose("2021-02-30");
+        assert_eq!(diagnostic.component(), Component::Day);
+        assert_eq!(diagnostic.span(), 8..10);
+        assert_eq!(diagnostic.suggestion(), Some(fuzzy_day(2021, 2, 28)));
+        assert!(diagnostic.to_string().contains("did you mean 2021-02-28?"));
+    }
+
+    #[test]
+    fn test_invalid_month_span() {
+        let diagnostic = diagnose("  2021-13");
+        assert_eq!(diagnostic.component(), Component::Month);
+        assert_eq!(diagnostic.span(), 7..9);
+        assert_eq!(diagnostic.expected(), EXPECTED_MONTH);
+        assert_eq!(diagnostic.suggestion(), None);
+    }
+
+    #[test]
+    fn test_swapped_month_and_day() {
+        let diagnostic = diagnose("13/04/2021");
+        assert_eq!(diagnostic.error(), &ParseError::InvalidMonth(13));
+        assert_eq!(diagnostic.component(), Component::Month);
+        assert_eq!(diagnostic.span(), 0..2);
+        assert_eq!(diagnostic.suggestion(), Some(fuzzy_day(2021, 4, 13)));
+    }
+
+    #[test]
+    fn test_invalid_year_span() {
+        let diagnostic = diagnose("08/15/0000");
+        assert_eq!(diagnostic.component(), Component::Year);
+        assert_eq!(diagnostic.span(), 6..10);
+    }
+
+    #[test]
+    fn test_mixed_separators() {
+        let diagnostic = diagnose("1991-08/15");
+        assert_eq!(diagnostic.component(), Component::Separator);
+        assert_eq!(diagnostic.span(), 7..8);
+        assert_eq!(diagnostic.expected(), "'-'");
+        assert_eq!(diagnostic.suggestion(), Some(fuzzy_day(1991, 8, 15)));
+    }
+
+    #[test]
+    fn test_non_numeric_component() {
+        let diagnostic = diagnose("1991-08-XX");
+        assert_eq!(diagnostic.component(), Component::Day);
+        assert_eq!(diagnostic.span(), 8..10);
+
+        let diagnostic = diagnose("02/XX/2020");
+        assert_eq!(diagnostic.component(), Component::Day);
+        assert_eq!(diagnostic.span(), 3..5);
+    }
+
+    #[test]
+    fn test_too_many_separators() {
+        let diagnostic = diagnose("2000-01-15-23");
+        assert_eq!(diagnostic.component(), Component::Separator);
+        assert_eq!(diagnostic.span(), 10..11);
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let diagnostic = diagnose("   ");
+        assert_eq!(diagnostic.error(), &ParseError::EmptyInput);
+        assert_eq!(diagnostic.component(), Component::Input);
+        assert_eq!(diagnostic.span(), 0..3);
+    }
+
+    #[test]
+    fn test_missing_separator() {
+        let diagnostic = diagnose_as(DateFormat::Dotted, "13042021");
+        assert_eq!(diagnostic.component(), Component::Separator);
+        assert_eq!(diagnostic.span(), 8..8);
+        assert_eq!(diagnostic.expected(), "'.'");
+
+        let diagnostic = diagnose_as(DateFormat::Dotted, "13/04/2021");
+        assert_eq!(diagnostic.component(), Component::Separator);
+        assert_eq!(diagnostic.span(), 2..3);
+        assert_eq!(diagnostic.suggestion(), Some(fuzzy_day(2021, 4, 13)));
+    }
+
+    #[test]
+    fn test_basic_fields() {
+        let diagnostic = diagnose_as(DateFormat::Basic, "20210230");
+        assert_eq!(diagnostic.component(), Component::Day);
+        assert_eq!(diagnostic.span(), 6..8);
+        assert_eq!(diagnostic.suggestion(), Some(fuzzy_day(2021, 2, 28)));
+
+        let diagnostic = diagnose_as(DateFormat::Basic, "2021-0230");
+        assert_eq!(diagnostic.component(), Component::Separator);
+        assert_eq!(diagnostic.span(), 4..5);
+    }
+
+    #[test]
+    fn test_month_name_fields() {
+        let diagnostic = diagnose_as(DateFormat::MonthName, "Febuary 30, 2021");
+        assert_eq!(diagnostic.component(), Component::Month);
+        assert_eq!(diagnostic.span(), 0..7);
+        assert_eq!(diagnostic.expected(), EXPECTED_MONTH_NAME);
+
+        let diagnostic = diagnose_as(DateFormat::MonthName, "Feb 30, 2021");
+        assert_eq!(diagnostic.component(), Component::Day);
+        assert_eq!(diagnostic.span(), 4..6);
+        assert_eq!(diagnostic.suggestion(), Some(fuzzy_day(2021, 2, 28)));
+    }
+
+    #[test]
+    fn test_unrecognised_shape_falls_back_to_input() {
+        let diagnostic = diagnose_as(DateFormat::Basic, "202102");
+        assert_eq!(diagnostic.component(), Component::Input);
+        assert_eq!(diagnostic.span(), 0..6);
+        assert_eq!(diagnostic.expected(), "YYYY or YYYYMMDD");
+    }
+}
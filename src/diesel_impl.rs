@@ -0,0 +1,305 @@
+//! `diesel` integration.
+//!
+//! `FuzzyDate` and `FuzzyDateRange` map to `Text` columns as ISO strings, and
+//! load from the integer column layouts of
+//! [`to_integer_columns`](FuzzyDate::to_integer_columns): a tuple of `Integer`
+//! year and `Nullable<Integer>` month and day, or six columns for a range.
+//! Writing text needs a backend feature, `diesel-sqlite` or `diesel-postgres`.
+
+use ::diesel::{
+    backend::Backend,
+    deserialize::{self, FromSql, FromStaticSqlRow, Queryable},
+    sql_types::{Integer, Nullable, Text},
+};
+
+use crate::{FuzzyDate, FuzzyDateRange};
+
+/// SQL type of the three-column layout.
+type Columns = (Integer, Nullable<Integer>, Nullable<Integer>);
+
+/// SQL type of the six-column range layout.
+type RangeColumns = (
+    Integer,
+    Nullable<Integer>,
+    Nullable<Integer>,
+    Integer,
+    Nullable<Integer>,
+    Nullable<Integer>,
+);
+
+impl<DB> FromSql<Text, DB> for FuzzyDate
+where
+    DB: Backend,
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        Ok(String::from_sql(bytes)?.parse()?)
+    }
+}
+
+impl<DB> FromSql<Text, DB> for FuzzyDateRange
+where
+    DB: Backend,
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        Ok(String::from_sql(bytes)?.parse()?)
+    }
+}
+
+impl<DB> Queryable<Text, DB> for FuzzyDate
+where
+    DB: Backend,
+    Self: FromSql<Text, DB>,
+{
+    type Row = Self;
+
+    fn build(row: Self) -> deserialize::Result<Self> {
+        Ok(row)
+    }
+}
+
+impl<DB> Queryable<Text, DB> for FuzzyDateRange
+where
+    DB: Backend,
+    Self: FromSql<Text, DB>,
+{
+    type Row = Self;
+
+    fn build(row: Self) -> deserialize::Result<Self> {
+        Ok(row)
+    }
+}
+
+impl<DB> Queryable<Columns, DB> for FuzzyDate
+where
+    DB: Backend,
+    (i32, Option<i32>, Option<i32>): FromStaticSqlRow<Columns, DB>,
+{
+    type Row = (i32, Option<i32>, Option<i32>);
+
+    fn build((year, month, day): Self::Row) -> deserialize::Result<Self> {
+        Ok(Self::from_integer_columns(year, month, day)?)
+    }
+}
+
+impl<DB> Queryable<RangeColumns, DB> for FuzzyDateRange
+where
+    DB: Backend,
+    (i32, Option<i32>, Option<i32>, i32, Option<i32>, Option<i32>):
+        FromStaticSqlRow<RangeColumns, DB>,
+{
+    type Row = (i32, Option<i32>, Option<i32>, i32, Option<i32>, Option<i32>);
+
+    fn build((sy, sm, sd, ey, em, ed): Self::Row) -> deserialize::Result<Self> {
+        Ok(Self::from_integer_columns(sy, sm, sd, ey, em, ed)?)
+    }
+}
+
+#[cfg(feature = "diesel-sqlite")]
+mod sqlite {
+    use ::diesel::{
+        serialize::{self, IsNull, Output, ToSql},
+        sql_types::Text,
+        sqlite::Sqlite,
+    };
+
+    use crate::{FuzzyDate, FuzzyDateRange};
+
+    impl ToSql<Text, Sqlite> for FuzzyDate {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+            out.set_value(self.to_string());
+            Ok(IsNull::No)
+        }
+    }
+
+    impl ToSql<Text, Sqlite> for FuzzyDateRange {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+            out.set_value(self.to_string());
+            Ok(IsNull::No)
+        }
+    }
+}
+
+#[cfg(feature = "diesel-postgres")]
+mod postgres {
+    use std::io::Write;
+
+    use ::diesel::{
+        pg::Pg,
+        serialize::{self, IsNull, Output, ToSql},
+        sql_types::Text,
+    };
+
+    use crate::{FuzzyDate, FuzzyDateRange};
+
+    impl ToSql<Text, Pg> for FuzzyDate {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+            write!(out, "{self}")?;
+            Ok(IsNull::No)
+        }
+    }
+
+    impl ToSql<Text, Pg> for FuzzyDateRange {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+            write!(out, "{self}")?;
+            Ok(IsNull::No)
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "diesel-sqlite")]
+mod tests {
+    use ::diesel::{prelude::*, sql_query, sqlite::SqliteConnection};
+
+    use super::*;
+    use crate::test_utils::{fuzzy_day, fuzzy_month, fuzzy_year};
+
+    ::diesel::table! {
+        people (id) {
+            id -> Integer,
+            born -> Text,
+            active -> Nullable<Text>,
+            born_year -> Integer,
+            born_month -> Nullable<Integer>,
+            born_day -> Nullable<Integer>,
+            active_start_year -> Integer,
+            active_start_month -> Nullable<Integer>,
+            active_start_day -> Nullable<Integer>,
+            active_end_year -> Integer,
+            active_end_month -> Nullable<Integer>,
+            active_end_day -> Nullable<Integer>,
+        }
+    }
+
+    #[derive(Queryable)]
+    struct Person {
+        born: FuzzyDate,
+        active: Option<FuzzyDateRange>,
+    }
+
+    fn connection() -> SqliteConnection {
+        let mut conn =
+            SqliteConnection::establish(":memory:").expect("expected in-memory database");
+        sql_query(
+            "CREATE TABLE people (
+                id INTEGER PRIMARY KEY, born TEXT NOT NULL, active TEXT,
+                born_year INTEGER NOT NULL, born_month INTEGER, born_day INTEGER,
+                active_start_year INTEGER NOT NULL, active_start_month INTEGER, active_start_day INTEGER,
+                active_end_year INTEGER NOT NULL, active_end_month INTEGER, active_end_day INTEGER
+            )",
+        )
+        .execute(&mut conn)
+        .expect("expected table");
+        conn
+    }
+
+    fn insert(conn: &mut SqliteConnection, id: i32, born: FuzzyDate, active: FuzzyDateRange) {
+        let (by, bm, bd) = born.to_integer_columns();
+        let (sy, sm, sd, ey, em, ed) = active.to_integer_columns();
+        ::diesel::insert_into(people::table)
+            .values((
+                people::id.eq(id),
+                people::born.eq(born),
+                people::active.eq(Some(active)),
+                people::born_year.eq(by),
+                people::born_month.eq(bm),
+                people::born_day.eq(bd),
+                people::active_start_year.eq(sy),
+                people::active_start_month.eq(sm),
+                people::active_start_day.eq(sd),
+                people::active_end_year.eq(ey),
+                people::active_end_month.eq(em),
+                people::active_end_day.eq(ed),
+            ))
+            .execute(conn)
+            .expect("expected insert");
+    }
+
+    fn range(start: FuzzyDate, end: FuzzyDate) -> FuzzyDateRange {
+        FuzzyDateRange::new(start, end).expect("expected valid test range")
+    }
+
+    #[test]
+    fn test_text_round_trip() {
+        let mut conn = connection();
+        let active = range(fuzzy_month(2020, 3), fuzzy_day(2026, 2, 13));
+        insert(&mut conn, 1, fuzzy_month(1991, 8), active);
+
+        let stored: String = people::table
+            .select(people::born)
+            .first::<FuzzyDate>(&mut conn)
+            .map(|date| date.to_string())
+            .expect("expected row");
+        assert_eq!(stored, "1991-08");
+
+        let person: Person = people::table
+            .select((people::born, people::active))
+            .first(&mut conn)
+            .expect("expected row");
+        assert_eq!(person.born, fuzzy_month(1991, 8));
+        assert_eq!(person.active, Some(active));
+
+        // Filtering by a bound value
+        let count: i64 = people::table
+            .filter(people::born.eq(fuzzy_month(1991, 8)))
+            .count()
+            .get_result(&mut conn)
+            .expect("expected count");
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn test_column_round_trip() {
+        let mut conn = connection();
+        let active = range(fuzzy_year(2010), fuzzy_day(2026, 2, 13));
+        insert(&mut conn, 1, fuzzy_day(1991, 8, 15), active);
+
+        let born: FuzzyDate = people::table
+            .select((people::born_year, people::born_month, people::born_day))
+            .first(&mut conn)
+            .expect("expected row");
+        assert_eq!(born, fuzzy_day(1991, 8, 15));
+
+        let loaded: FuzzyDateRange = people::table
+            .select((
+                people::active_start_year,
+                people::active_start_month,
+                people::active_start_day,
+                people::active_end_year,
+                people::active_end_month,
+                people::active_end_day,
+            ))
+            .first(&mut conn)
+            .expect("expected row");
+        assert_eq!(loaded, active);
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        let mut conn = connection();
+        insert(
+            &mut conn,
+            1,
+            fuzzy_year(1991),
+            range(fuzzy_year(2010), fuzzy_year(2020)),
+        );
+        sql_query("UPDATE people SET born_month = 13, active = '2020/2010'")
+            .execute(&mut conn)
+            .expect("expected update");
+
+        assert!(
+            people::table
+                .select((people::born_year, people::born_month, people::born_day))
+                .first::<FuzzyDate>(&mut conn)
+                .is_err()
+        );
+        assert!(
+            people::table
+                .select(people::active)
+                .first::<Option<FuzzyDateRange>>(&mut conn)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use std::ops::RangeInclusive;
+
+use crate::{FuzzyDate, FuzzyDateRange, MAX_MONTH, MAX_YEAR, Precision, Year, days_in_month};
+
+/// Controls how the `proptest`, `quickcheck` and `arbitrary` generators draw
+/// fuzzy dates: the span of years and how often each precision appears.
+///
+/// Generated days are always valid for their month and year.
+///
+/// ```
+/// use fuzzy_date::{DateDistribution, Year};
+///
+/// let recent = DateDistribution::new()
+///     .with_years(Year::new(2000)?, Year::new(2030)?)
+///     .with_precision_weights(1, 1, 8);
+/// assert_eq!(recent.years(), 2000..=2030);
+/// # Ok::<(), fuzzy_date::ParseError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateDistribution {
+    first_year: u16,
+    last_year: u16,
+    weights: (u32, u32, u32),
+}
+
+impl DateDistribution {
+    /// Every year from 1 to 9999, with each precision equally likely.
+    pub const fn new() -> Self {
+        Self {
+            first_year: 1,
+            last_year: MAX_YEAR,
+            weights: (1, 1, 1),
+        }
+    }
+
+    /// Restricts generated years to `first..=last`. The bounds are swapped if
+    /// given in reverse.
+    #[must_use]
+    pub const fn with_years(mut self, first: Year, last: Year) -> Self {
+        let (first, last) = (first.get(), last.get());
+        if first <= last {
+            (self.first_year, self.last_year) = (first, last);
+        } else {
+            (self.first_year, self.last_year) = (last, first);
+        }
+        self
+    }
+
+    /// Sets the relative weights of year, month and day precision. A zero
+    /// weight excludes that precision; if every weight is zero, all three are
+    /// equally likely.
+    #[must_use]
+    pub const fn with_precision_weights(mut self, year: u32, month: u32, day: u32) -> Self {
+        self.weights = (year, month, day);
+        self
+    }
+
+    /// Returns the span of generated years.
+    pub const fn years(&self) -> RangeInclusive<u16> {
+        self.first_year..=self.last_year
+    }
+
+    /// Returns the year, month and day precision weights.
+    pub const fn precision_weights(&self) -> (u32, u32, u32) {
+        self.weights
+    }
+}
+
+impl Default for DateDistribution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+const fn effective_weights(dist: &DateDistribution) -> [u64; 3] {
+    let (year, month, day) = dist.weights;
+    if year == 0 && month == 0 && day == 0 {
+        [1, 1, 1]
+    } else {
+        [year as u64, month as u64, day as u64]
+    }
+}
+
+/// Exclusive upper bound of the precision draw passed to [`date`].
+pub const fn weight_total(dist: &DateDistribution) -> u64 {
+    let [year, month, day] = effective_weights(dist);
+    year + month + day
+}
+
+/// Maps raw draws onto a date in `dist`.
+///
+/// `year` is clamped into the span, `pick` below [`weight_total`] selects the
+/// precision (year, then month, then day, so smaller picks are coarser),
+/// `month` is clamped to `1..=12`, and `day` to the length of the month. The
+/// clamping keeps every component shrinking towards its smallest valid value.
+pub fn date(dist: &DateDistribution, year: u16, pick: u64, month: u8, day: u8) -> FuzzyDate {
+    let year = year.clamp(dist.first_year, dist.last_year);
+    let month = month.clamp(1, MAX_MONTH);
+    let day = day.clamp(1, days_in_month(year, month));
+
+    let [year_weight, month_weight, _] = effective_weights(dist);
+    let precision = if pick < year_weight {
+        Precision::Year
+    } else if pick < year_weight + month_weight {
+        Precision::Month
+    } else {
+        Precision::Day
+    };
+
+    FuzzyDate::from_columns(year, Some(month), Some(day))
+        .unwrap_or_else(|_| unreachable!("clamped components are always a valid date"))
+        .truncate(precision)
+}
+
+/// Orders two dates into a range.
+pub fn range(a: FuzzyDate, b: FuzzyDate) -> FuzzyDateRange {
+    let (start, end) = if a <= b { (a, b) } else { (b, a) };
+    FuzzyDateRange::new(start, end)
+        .unwrap_or_else(|_| unreachable!("ordered dates are always a valid range"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{fuzzy_day, fuzzy_month, fuzzy_year, year};
+
+    #[test]
+    fn test_date_clamps_components() {
+        let dist = DateDistribution::new().with_years(year(1990), year(2000));
+        assert_eq!(date(&dist, 2024, 2, 2, 31), fuzzy_day(2000, 2, 29));
+        assert_eq!(date(&dist, 0, 2, 0, 0), fuzzy_day(1990, 1, 1));
+        assert_eq!(date(&dist, 1995, 1, 13, 1), fuzzy_month(1995, 12));
+        assert_eq!(date(&dist, 1995, 0, 6, 15), fuzzy_year(1995));
+    }
+
+    #[test]
+    fn test_precision_weights() {
+        let dist = DateDistribution::new().with_precision_weights(0, 3, 1);
+        assert_eq!(weight_total(&dist), 4);
+        let precisions: Vec<_> = (0..4)
+            .map(|pick| date(&dist, 2024, pick, 6, 15).precision())
+            .collect();
+        assert_eq!(
+            precisions,
+            [
+                Precision::Month,
+                Precision::Month,
+                Precision::Month,
+                Precision::Day
+            ]
+        );
+
+        let zero = DateDistribution::new().with_precision_weights(0, 0, 0);
+        assert_eq!(weight_total(&zero), 3);
+        assert_eq!(date(&zero, 2024, 0, 6, 15).precision(), Precision::Year);
+    }
+
+    #[test]
+    fn test_reversed_years() {
+        let dist = DateDistribution::new().with_years(year(2030), year(2000));
+        assert_eq!(dist.years(), 2000..=2030);
+    }
+
+    #[test]
+    fn test_range_orders_dates() {
+        let span = range(fuzzy_year(2024), fuzzy_month(2020, 5));
+        assert_eq!(span.dates(), (fuzzy_month(2020, 5), fuzzy_year(2024)));
+    }
+}
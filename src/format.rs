@@ -0,0 +1,513 @@
+use crate::{FieldOrder, FuzzyDate, MAX_YEAR, ParseError, prelude::*};
+
+/// A textual date layout that a [`Parser`] can be configured to accept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DateFormat {
+    /// ISO 8601 extended: `YYYY`, `YYYY-MM`, `YYYY-MM-DD`
+    Iso,
+    /// US month-first: `MM/YYYY`, `MM/DD/YYYY`
+    MonthFirst,
+    /// Day-first: `MM/YYYY`, `DD/MM/YYYY`
+    DayFirst,
+    /// Dotted day-first: `MM.YYYY`, `DD.MM.YYYY`
+    Dotted,
+    /// ISO 8601 basic: `YYYY`, `YYYYMMDD`
+    Basic,
+    /// English month names: `Feb 2026`, `13 Feb 2026`, `February 13, 2026`
+    MonthName,
+}
+
+impl DateFormat {
+    /// Every supported format, in order of preference when several fit the same input.
+    pub const ALL: [Self; 6] = [
+        Self::Iso,
+        Self::MonthFirst,
+        Self::DayFirst,
+        Self::Dotted,
+        Self::Basic,
+        Self::MonthName,
+    ];
+
+    /// Position of this format within [`DateFormat::ALL`].
+    pub(crate) const fn index(self) -> usize {
+        match self {
+            Self::Iso => 0,
+            Self::MonthFirst => 1,
+            Self::DayFirst => 2,
+            Self::Dotted => 3,
+            Self::Basic => 4,
+            Self::MonthName => 5,
+        }
+    }
+}
+
+/// How the month-first, day-first and dotted formats read a year field of
+/// exactly two digits, such as the `91` in `08/15/91`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TwoDigitYears {
+    /// Read the digits as written: `91` is the year 91.
+    #[default]
+    Literal,
+    /// Map into the hundred years starting at the given year:
+    /// with `Pivot(1950)`, `50` is 1950 and `49` is 2049.
+    Pivot(u16),
+    /// Map into the hundred years ending `ahead` years after `reference`:
+    /// with `reference: 2026, ahead: 10`, `36` is 2036 and `37` is 1937.
+    Window { reference: u16, ahead: u16 },
+    /// Fail with `ParseError::TwoDigitYear`.
+    Reject,
+}
+
+impl TwoDigitYears {
+    /// Resolves the two-digit year `yy` (0..=99) to a full year.
+    ///
+    /// # Errors
+    /// Returns `ParseError::TwoDigitYear` if two-digit years are rejected, or
+    /// `ParseError::InvalidYear` if the result falls outside 1..=9999.
+    pub fn resolve(self, yy: u8) -> Result<u16, ParseError> {
+        let first = match self {
+            Self::Literal => return Ok(u16::from(yy)),
+            Self::Pivot(first) => first,
+            Self::Window { reference, ahead } => {
+                (reference.saturating_add(ahead)).saturating_sub(99)
+            }
+            Self::Reject => return Err(ParseError::TwoDigitYear(yy)),
+        };
+
+        // Earliest year on or after `first` ending in `yy`
+        let offset = (u16::from(yy) + 100 - first % 100) % 100;
+        let year = first.saturating_add(offset);
+        if year == 0 || year > MAX_YEAR {
+            return Err(ParseError::InvalidYear(year));
+        }
+        Ok(year)
+    }
+}
+
+/// Parses fuzzy dates in a single, explicitly chosen [`DateFormat`].
+///
+/// Unlike `FromStr`, which accepts ISO and US month-first input, a `Parser`
+/// only accepts its configured format, so ambiguous inputs such as
+/// `03/04/2021` are always read the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Parser {
+    format: DateFormat,
+    two_digit_years: TwoDigitYears,
+    clamp_days: bool,
+    precision_fallback: bool,
+}
+
+impl Parser {
+    /// Creates a parser for the given format.
+    pub const fn new(format: DateFormat) -> Self {
+        Self {
+            format,
+            two_digit_years: TwoDigitYears::Literal,
+            clamp_days: false,
+            precision_fallback: false,
+        }
+    }
+
+    /// Returns the format this parser accepts.
+    pub const fn format(&self) -> DateFormat {
+        self.format
+    }
+
+    /// Sets how a two-digit year is read by the month-first, day-first and
+    /// dotted formats. Defaults to [`TwoDigitYears::Literal`], matching `FromStr`.
+    #[must_use]
+    pub const fn with_two_digit_years(mut self, years: TwoDigitYears) -> Self {
+        self.two_digit_years = years;
+        self
+    }
+
+    /// Returns how this parser reads two-digit years.
+    pub const fn two_digit_years(&self) -> TwoDigitYears {
+        self.two_digit_years
+    }
+
+    /// In [`parse_lenient`](Self::parse_lenient), clamp a day past the end of
+    /// its month to the month's last day (`02/30/2021` becomes `2021-02-28`).
+    #[must_use]
+    pub const fn with_day_clamping(mut self, clamp: bool) -> Self {
+        self.clamp_days = clamp;
+        self
+    }
+
+    /// In [`parse_lenient`](Self::parse_lenient), drop an invalid day or month
+    /// and keep the coarser precision that remains (`2021-13` becomes `2021`).
+    /// Day clamping, if enabled, is tried first.
+    #[must_use]
+    pub const fn with_precision_fallback(mut self, fallback: bool) -> Self {
+        self.precision_fallback = fallback;
+        self
+    }
+
+    /// Returns whether lenient parsing clamps out-of-range days.
+    pub const fn clamps_days(&self) -> bool {
+        self.clamp_days
+    }
+
+    /// Returns whether lenient parsing falls back to coarser precision.
+    pub const fn falls_back_to_coarser_precision(&self) -> bool {
+        self.precision_fallback
+    }
+
+    /// Parses `s` in this parser's format. Leading and trailing whitespace is ignored.
+    ///
+    /// # Errors
+    /// Returns `ParseError::EmptyInput` for blank input, `ParseError::InvalidFormat`
+    /// if the input does not match the format, and the component errors if the
+    /// year, month, or day is out of range.
+    pub fn parse(&self, s: &str) -> Result<FuzzyDate, ParseError> {
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            return Err(ParseError::EmptyInput);
+        }
+
+        match self.format {
+            DateFormat::Iso => FuzzyDate::parse_iso(trimmed),
+            DateFormat::MonthFirst => FuzzyDate::parse_separated(
+                trimmed,
+                b'/',
+                FieldOrder::MonthFirst,
+                self.two_digit_years,
+            ),
+            DateFormat::DayFirst => FuzzyDate::parse_separated(
+                trimmed,
+                b'/',
+                FieldOrder::DayFirst,
+                self.two_digit_years,
+            ),
+            DateFormat::Dotted => FuzzyDate::parse_separated(
+                trimmed,
+                b'.',
+                FieldOrder::DayFirst,
+                self.two_digit_years,
+            ),
+            DateFormat::Basic => parse_basic(trimmed),
+            DateFormat::MonthName => parse_month_name(trimmed),
+        }
+    }
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Self::new(DateFormat::Iso)
+    }
+}
+
+impl From<DateFormat> for Parser {
+    fn from(format: DateFormat) -> Self {
+        Self::new(format)
+    }
+}
+
+/// Parse ISO 8601 basic format: `YYYY` or `YYYYMMDD`.
+fn parse_basic(s: &str) -> Result<FuzzyDate, ParseError> {
+    if !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseError::InvalidFormat(s.to_owned()));
+    }
+
+    match s.len() {
+        4 => FuzzyDate::parse_iso(s),
+        8 => {
+            let year = parse_number(&s[..4], s)?;
+            let month = parse_number(&s[4..6], s)?;
+            let day = parse_number(&s[6..], s)?;
+            FuzzyDate::from_columns(year, Some(month), Some(day))
+        }
+        _ => Err(ParseError::InvalidFormat(s.to_owned())),
+    }
+}
+
+/// Lowercase English month names, January first.
+const MONTH_NAMES: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+/// Resolves a full or three-letter English month name (case-insensitive,
+/// optional trailing `.`) to its month number.
+pub fn month_from_name(token: &str) -> Option<u8> {
+    let name = token.strip_suffix('.').unwrap_or(token);
+    if name.len() < 3 {
+        return None;
+    }
+
+    MONTH_NAMES
+        .iter()
+        .zip(1u8..)
+        .find(|(full, _)| {
+            name.eq_ignore_ascii_case(full)
+                || name.eq_ignore_ascii_case(&full[..3])
+                || (**full == "september" && name.eq_ignore_ascii_case("sept"))
+        })
+        .map(|(_, month)| month)
+}
+
+/// Parse month-name dates: `Mon YYYY`, `D Mon YYYY` and `Mon D, YYYY`.
+/// Commas are treated as whitespace.
+fn parse_month_name(s: &str) -> Result<FuzzyDate, ParseError> {
+    let err = || ParseError::InvalidFormat(s.to_owned());
+
+    let mut tokens = s
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|t| !t.is_empty());
+    let fields = [tokens.next(), tokens.next(), tokens.next()];
+    if tokens.next().is_some() {
+        return Err(err());
+    }
+
+    match fields {
+        [Some(month), Some(year), None] => {
+            let month = month_from_name(month).ok_or_else(err)?;
+            let year = parse_number(year, s)?;
+            FuzzyDate::from_columns(year, Some(month), None)
+        }
+        [Some(first), Some(second), Some(year)] => {
+            let (month, day) = match (month_from_name(first), month_from_name(second)) {
+                (Some(month), None) => (month, second),
+                (None, Some(month)) => (month, first),
+                _ => return Err(err()),
+            };
+            let day = parse_number(day, s)?;
+            let year = parse_number(year, s)?;
+            FuzzyDate::from_columns(year, Some(month), Some(day))
+        }
+        _ => Err(err()),
+    }
+}
+
+/// Parses an all-digit field, reporting the whole input on failure.
+fn parse_number<T: core::str::FromStr>(field: &str, input: &str) -> Result<T, ParseError> {
+    if field.is_empty() || !field.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseError::InvalidFormat(input.to_owned()));
+    }
+    field
+        .parse()
+        .map_err(|_| ParseError::InvalidFormat(input.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{fuzzy_day, fuzzy_month, fuzzy_year};
+
+    fn parse(format: DateFormat, input: &str) -> Result<FuzzyDate, ParseError> {
+        Parser::new(format).parse(input)
+    }
+
+    #[test]
+    fn test_iso() {
+        assert_eq!(parse(DateFormat::Iso, "2021"), Ok(fuzzy_year(2021)));
+        assert_eq!(parse(DateFormat::Iso, "2021-03"), Ok(fuzzy_month(2021, 3)));
+        assert_eq!(
+            parse(DateFormat::Iso, "2021-03-04"),
+            Ok(fuzzy_day(2021, 3, 4))
+        );
+        assert!(parse(DateFormat::Iso, "03/04/2021").is_err());
+    }
+
+    #[test]
+    fn test_month_first_and_day_first() {
+        assert_eq!(
+            parse(DateFormat::MonthFirst, "03/04/2021"),
+            Ok(fuzzy_day(2021, 3, 4))
+        );
+        assert_eq!(
+            parse(DateFormat::DayFirst, "03/04/2021"),
+            Ok(fuzzy_day(2021, 4, 3))
+        );
+
+        // Two fields are always month then year
+        assert_eq!(
+            parse(DateFormat::MonthFirst, "03/2021"),
+            Ok(fuzzy_month(2021, 3))
+        );
+        assert_eq!(
+            parse(DateFormat::DayFirst, "03/2021"),
+            Ok(fuzzy_month(2021, 3))
+        );
+
+        assert!(matches!(
+            parse(DateFormat::MonthFirst, "13/04/2021"),
+            Err(ParseError::InvalidMonth(13))
+        ));
+        assert_eq!(
+            parse(DateFormat::DayFirst, "13/04/2021"),
+            Ok(fuzzy_day(2021, 4, 13))
+        );
+        assert!(parse(DateFormat::DayFirst, "2021-04-13").is_err());
+    }
+
+    #[test]
+    fn test_dotted() {
+        assert_eq!(
+            parse(DateFormat::Dotted, "13.04.2021"),
+            Ok(fuzzy_day(2021, 4, 13))
+        );
+        assert_eq!(
+            parse(DateFormat::Dotted, "4.2021"),
+            Ok(fuzzy_month(2021, 4))
+        );
+        assert!(parse(DateFormat::Dotted, "13/04/2021").is_err());
+        assert!(matches!(
+            parse(DateFormat::Dotted, "30.02.2021"),
+            Err(ParseError::InvalidDay { .. })
+        ));
+    }
+
+    #[test]
+    fn test_basic() {
+        assert_eq!(
+            parse(DateFormat::Basic, "20210304"),
+            Ok(fuzzy_day(2021, 3, 4))
+        );
+        assert_eq!(parse(DateFormat::Basic, "2021"), Ok(fuzzy_year(2021)));
+
+        // YYYYMM is not valid ISO 8601 basic format
+        assert!(parse(DateFormat::Basic, "202103").is_err());
+        assert!(parse(DateFormat::Basic, "2021-03-04").is_err());
+        assert!(parse(DateFormat::Basic, "2021+304").is_err());
+        assert!(matches!(
+            parse(DateFormat::Basic, "20211304"),
+            Err(ParseError::InvalidMonth(13))
+        ));
+    }
+
+    #[test]
+    fn test_month_name() {
+        assert_eq!(
+            parse(DateFormat::MonthName, "Feb 2026"),
+            Ok(fuzzy_month(2026, 2))
+        );
+        assert_eq!(
+            parse(DateFormat::MonthName, "february 2026"),
+            Ok(fuzzy_month(2026, 2))
+        );
+        assert_eq!(
+            parse(DateFormat::MonthName, "13 Feb 2026"),
+            Ok(fuzzy_day(2026, 2, 13))
+        );
+        assert_eq!(
+            parse(DateFormat::MonthName, "February 13, 2026"),
+            Ok(fuzzy_day(2026, 2, 13))
+        );
+        assert_eq!(
+            parse(DateFormat::MonthName, "Sept. 1 2026"),
+            Ok(fuzzy_day(2026, 9, 1))
+        );
+
+        assert!(parse(DateFormat::MonthName, "2026").is_err());
+        assert!(parse(DateFormat::MonthName, "Fe 2026").is_err());
+        assert!(parse(DateFormat::MonthName, "Feb Mar 2026").is_err());
+        assert!(parse(DateFormat::MonthName, "13 Feb 2026 extra").is_err());
+        assert!(matches!(
+            parse(DateFormat::MonthName, "Feb 30 2026"),
+            Err(ParseError::InvalidDay { .. })
+        ));
+    }
+
+    #[test]
+    fn test_two_digit_years_default_literal() {
+        assert_eq!(
+            parse(DateFormat::MonthFirst, "08/15/91"),
+            Ok(fuzzy_day(91, 8, 15))
+        );
+    }
+
+    #[test]
+    fn test_two_digit_years_pivot() {
+        let parser =
+            Parser::new(DateFormat::MonthFirst).with_two_digit_years(TwoDigitYears::Pivot(1950));
+        assert_eq!(parser.parse("08/15/91"), Ok(fuzzy_day(1991, 8, 15)));
+        assert_eq!(parser.parse("08/91"), Ok(fuzzy_month(1991, 8)));
+        assert_eq!(parser.parse("08/49"), Ok(fuzzy_month(2049, 8)));
+        assert_eq!(parser.parse("08/50"), Ok(fuzzy_month(1950, 8)));
+
+        // Only exactly two digits are expanded
+        assert_eq!(parser.parse("08/0091"), Ok(fuzzy_month(91, 8)));
+        assert_eq!(parser.parse("08/15/1991"), Ok(fuzzy_day(1991, 8, 15)));
+
+        // The expanded year decides leap days
+        let dotted =
+            Parser::new(DateFormat::Dotted).with_two_digit_years(TwoDigitYears::Pivot(1950));
+        assert_eq!(dotted.parse("29.02.00"), Ok(fuzzy_day(2000, 2, 29)));
+    }
+
+    #[test]
+    fn test_two_digit_years_window() {
+        let years = TwoDigitYears::Window {
+            reference: 2026,
+            ahead: 10,
+        };
+        assert_eq!(years.resolve(36), Ok(2036));
+        assert_eq!(years.resolve(37), Ok(1937));
+        assert_eq!(years.resolve(26), Ok(2026));
+
+        let parser = Parser::new(DateFormat::DayFirst).with_two_digit_years(years);
+        assert_eq!(parser.parse("15/08/91"), Ok(fuzzy_day(1991, 8, 15)));
+    }
+
+    #[test]
+    fn test_two_digit_years_reject() {
+        let parser =
+            Parser::new(DateFormat::MonthFirst).with_two_digit_years(TwoDigitYears::Reject);
+        assert_eq!(parser.parse("08/15/91"), Err(ParseError::TwoDigitYear(91)));
+        assert_eq!(parser.parse("08/05"), Err(ParseError::TwoDigitYear(5)));
+        assert_eq!(parser.parse("08/15/1991"), Ok(fuzzy_day(1991, 8, 15)));
+    }
+
+    #[test]
+    fn test_two_digit_years_out_of_range() {
+        assert_eq!(
+            TwoDigitYears::Pivot(0).resolve(0),
+            Err(ParseError::InvalidYear(0))
+        );
+        assert_eq!(
+            TwoDigitYears::Pivot(9950).resolve(10),
+            Err(ParseError::InvalidYear(10010))
+        );
+    }
+
+    #[test]
+    fn test_two_digit_years_ignored_by_iso() {
+        let parser = Parser::new(DateFormat::Iso).with_two_digit_years(TwoDigitYears::Reject);
+        assert_eq!(parser.parse("91"), Ok(fuzzy_year(91)));
+    }
+
+    #[test]
+    fn test_empty_input() {
+        for format in DateFormat::ALL {
+            assert_eq!(parse(format, "  "), Err(ParseError::EmptyInput));
+        }
+    }
+
+    #[test]
+    fn test_index_matches_all() {
+        for (i, format) in DateFormat::ALL.into_iter().enumerate() {
+            assert_eq!(format.index(), i);
+        }
+    }
+
+    #[test]
+    fn test_agrees_with_from_str() {
+        for input in ["1991", "1991-08", "1991-08-15"] {
+            assert_eq!(parse(DateFormat::Iso, input), input.parse());
+        }
+        for input in ["08/1991", "08/15/1991", "8/5/1991"] {
+            assert_eq!(parse(DateFormat::MonthFirst, input), input.parse());
+        }
+    }
+}
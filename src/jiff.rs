@@ -0,0 +1,31 @@
+//! `jiff` integration.
+
+use ::jiff::civil::Date;
+
+use crate::calendar::{CalendarDate, impl_calendar_date};
+
+impl_calendar_date!(Date);
+
+impl CalendarDate for Date {
+    #[allow(clippy::cast_possible_wrap)] // years are at most 9999, months and days at most 31
+    fn from_ymd(year: u16, month: u8, day: u8) -> Self {
+        Self::new(year as i16, month as i8, day as i8)
+            .unwrap_or_else(|_| unreachable!("fuzzy date components are always a valid Date"))
+    }
+
+    #[allow(clippy::cast_sign_loss)] // months and days are positive
+    fn to_ymd(self) -> (i32, u8, u8) {
+        (self.year().into(), self.month() as u8, self.day() as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::test_utils::check_calendar_date;
+
+    #[test]
+    fn test_calendar_date() {
+        check_calendar_date(Date::new(-9999, 1, 1).expect("expected valid test date"));
+    }
+}
@@ -0,0 +1,315 @@
+//! Order-preserving binary keys for bytewise-sorted stores.
+//!
+//! A `FuzzyDate` key is four bytes: the big-endian year, the month of the
+//! lower bound, and the day of the lower bound shifted left by two with the
+//! precision rank in the low bits. Comparing keys bytewise therefore compares
+//! lower bounds first and breaks ties Year < Month < Day, exactly as `Ord`
+//! does. A `FuzzyDateRange` key is the start key followed by the end key.
+
+use core::ops::RangeInclusive;
+
+use crate::{
+    ByteParseError, ByteRangeError, FuzzyDate, FuzzyDateRange,
+    bytes::{day_from, month_from, year_from},
+};
+
+/// Precision rank stored in the low bits of the last key byte.
+const RANK_BITS: u32 = 2;
+const RANK_MASK: u8 = (1 << RANK_BITS) - 1;
+const DAY_RANK: u8 = 2;
+
+impl FuzzyDate {
+    /// Length of the key produced by [`to_key`](Self::to_key).
+    pub const KEY_LEN: usize = 4;
+
+    /// Encodes this value as a key whose bytewise order matches `Ord`.
+    pub const fn to_key(&self) -> [u8; 4] {
+        let (year, month, day) = self.lower_bound();
+        let [hi, lo] = year.to_be_bytes();
+        [hi, lo, month, (day << RANK_BITS) | self.precision_rank()]
+    }
+
+    /// Decodes a key produced by [`to_key`](Self::to_key).
+    ///
+    /// # Errors
+    /// Returns `InvalidYear`, `InvalidMonth` or `InvalidDay` for out-of-range
+    /// components, and `InvalidFormat` with the offset of the first byte that
+    /// differs from the canonical encoding, such as an unknown precision rank
+    /// or a non-zero day on a month-precision key.
+    pub fn from_key(key: [u8; 4]) -> Result<Self, ByteParseError> {
+        let [hi, lo, month, tail] = key;
+        let year = u32::from(u16::from_be_bytes([hi, lo]));
+        let (month, day) = (u32::from(month), u32::from(tail >> RANK_BITS));
+        let date = match tail & RANK_MASK {
+            0 => Self::Year {
+                year: year_from(year)?,
+            },
+            1 => Self::Month {
+                year: year_from(year)?,
+                month: month_from(month)?,
+            },
+            DAY_RANK => Self::Day {
+                year: year_from(year)?,
+                month: month_from(month)?,
+                day: day_from(year, month, day)?,
+            },
+            _ => return Err(ByteParseError::InvalidFormat(3)),
+        };
+
+        let canonical = date.to_key();
+        if let Some(at) = (0..Self::KEY_LEN).find(|&i| canonical[i] != key[i]) {
+            return Err(ByteParseError::InvalidFormat(at));
+        }
+        Ok(date)
+    }
+
+    /// Inclusive span of keys for every value contained in this one.
+    ///
+    /// All keys in the span share this value's first
+    /// [`key_prefix_len`](Self::key_prefix_len) bytes, so a range or prefix
+    /// scan starting at [`to_key`](Self::to_key) finds everything recorded
+    /// within a year or month, at any precision, and nothing else.
+    pub const fn key_range(&self) -> RangeInclusive<[u8; 4]> {
+        let (year, month, day) = self.upper_bound_inclusive();
+        let [hi, lo] = year.to_be_bytes();
+        RangeInclusive::new(
+            self.to_key(),
+            [hi, lo, month, (day << RANK_BITS) | DAY_RANK],
+        )
+    }
+
+    /// Number of leading key bytes shared by every value contained in this
+    /// one: 2 for a year, 3 for a month and 4 for a day.
+    ///
+    /// A month prefix also matches the year-precision key when the month is
+    /// January, which sorts first; start the scan at [`to_key`](Self::to_key)
+    /// to skip it.
+    pub const fn key_prefix_len(&self) -> usize {
+        match self {
+            Self::Year { .. } => 2,
+            Self::Month { .. } => 3,
+            Self::Day { .. } => 4,
+        }
+    }
+}
+
+impl FuzzyDateRange {
+    /// Length of the key produced by [`to_key`](Self::to_key).
+    pub const KEY_LEN: usize = 2 * FuzzyDate::KEY_LEN;
+
+    /// Encodes this range as the start key followed by the end key, so its
+    /// bytewise order matches `Ord`.
+    pub const fn to_key(&self) -> [u8; 8] {
+        concat(self.start().to_key(), self.end().to_key())
+    }
+
+    /// Decodes a key produced by [`to_key`](Self::to_key).
+    ///
+    /// # Errors
+    /// Returns the `ByteParseError` of either half, with offsets relative to
+    /// the whole key, or `InvalidRange` if the start is after the end.
+    pub fn from_key(key: [u8; 8]) -> Result<Self, ByteRangeError> {
+        let [start @ .., _, _, _, _] = key;
+        let [_, _, _, _, end @ ..] = key;
+        let start = FuzzyDate::from_key(start)?;
+        let end = FuzzyDate::from_key(end).map_err(|err| match err {
+            ByteParseError::InvalidFormat(at) => {
+                ByteParseError::InvalidFormat(at + FuzzyDate::KEY_LEN)
+            }
+            err => err,
+        })?;
+        Self::new(start, end).map_err(|_| ByteRangeError::InvalidRange { start, end })
+    }
+
+    /// Inclusive span of keys for every range whose start is contained in
+    /// `date`.
+    pub const fn key_range_starting_within(date: &FuzzyDate) -> RangeInclusive<[u8; 8]> {
+        let range = date.key_range();
+        RangeInclusive::new(
+            concat(*range.start(), [0; 4]),
+            concat(*range.end(), [u8::MAX; 4]),
+        )
+    }
+}
+
+/// Joins a start and end key into a range key.
+const fn concat(start: [u8; 4], end: [u8; 4]) -> [u8; 8] {
+    let mut key = [0; 8];
+    let mut i = 0;
+    while i < FuzzyDate::KEY_LEN {
+        key[i] = start[i];
+        key[i + FuzzyDate::KEY_LEN] = end[i];
+        i += 1;
+    }
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::test_utils::{fuzzy_day, fuzzy_month, fuzzy_year};
+
+    /// Every value in the given years, at every precision.
+    fn all_dates(years: &[u16]) -> Vec<FuzzyDate> {
+        let mut dates = Vec::new();
+        for &year in years {
+            dates.push(fuzzy_year(year));
+            for month in 1..=12 {
+                dates.push(fuzzy_month(year, month));
+                for day in 1..=crate::types::days_in_month(year, month) {
+                    dates.push(fuzzy_day(year, month, day));
+                }
+            }
+        }
+        dates
+    }
+
+    #[test]
+    fn test_key_layout() {
+        assert_eq!(fuzzy_year(2024).to_key(), [0x07, 0xE8, 1, 4]);
+        assert_eq!(fuzzy_month(2024, 3).to_key(), [0x07, 0xE8, 3, 5]);
+        assert_eq!(fuzzy_day(2024, 3, 31).to_key(), [0x07, 0xE8, 3, 126]);
+    }
+
+    #[test]
+    fn test_key_round_trip() {
+        for date in all_dates(&[1, 2000, 2023, 2024, 9999]) {
+            assert_eq!(FuzzyDate::from_key(date.to_key()), Ok(date), "{date}");
+        }
+    }
+
+    #[test]
+    fn test_key_order_matches_ord() {
+        let mut by_ord = all_dates(&[1, 2, 1900, 1999, 2000, 2024, 9998, 9999]);
+        // Interleave so neither sort starts from already sorted input
+        by_ord.reverse();
+        let third = by_ord.len() / 3;
+        by_ord.rotate_left(third);
+        let mut by_key = by_ord.clone();
+
+        by_ord.sort();
+        by_key.sort_by_key(FuzzyDate::to_key);
+        assert_eq!(by_ord, by_key);
+
+        for pair in by_ord.windows(2) {
+            assert!(pair[0] < pair[1]);
+            assert!(
+                pair[0].to_key() < pair[1].to_key(),
+                "{} {}",
+                pair[0],
+                pair[1]
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_keys() {
+        let cases = [
+            ([0, 0, 1, 4], ByteParseError::InvalidYear(0)),
+            ([0x27, 0x10, 1, 4], ByteParseError::InvalidYear(10_000)),
+            ([0x07, 0xE8, 13, 5], ByteParseError::InvalidMonth(13)),
+            (
+                [0x07, 0xE7, 2, (29 << 2) | 2],
+                ByteParseError::InvalidDay {
+                    month: 2,
+                    day: 29,
+                    year: 2023,
+                },
+            ),
+            ([0x07, 0xE8, 1, 7], ByteParseError::InvalidFormat(3)),
+            ([0x07, 0xE8, 2, 4], ByteParseError::InvalidFormat(2)),
+            ([0x07, 0xE8, 1, 8], ByteParseError::InvalidFormat(3)),
+            ([0x07, 0xE8, 3, 9], ByteParseError::InvalidFormat(3)),
+        ];
+        for (key, expected) in cases {
+            assert_eq!(FuzzyDate::from_key(key), Err(expected), "{key:?}");
+        }
+    }
+
+    #[test]
+    fn test_key_range_scan() {
+        let store: BTreeMap<[u8; 4], FuzzyDate> = all_dates(&[2023, 2024, 2025])
+            .into_iter()
+            .map(|date| (date.to_key(), date))
+            .collect();
+
+        for scope in [
+            fuzzy_year(2024),
+            fuzzy_month(2024, 1),
+            fuzzy_month(2024, 2),
+            fuzzy_day(2024, 2, 29),
+        ] {
+            let found: Vec<FuzzyDate> = store.range(scope.key_range()).map(|(_, d)| *d).collect();
+            let expected: Vec<FuzzyDate> = store
+                .values()
+                .copied()
+                .filter(|date| scope.contains(date))
+                .collect();
+            assert_eq!(found, expected, "{scope}");
+
+            let len = scope.key_prefix_len();
+            let key = scope.to_key();
+            let prefixed: Vec<FuzzyDate> = store
+                .range(key..)
+                .take_while(|(k, _)| k[..len] == key[..len])
+                .map(|(_, d)| *d)
+                .collect();
+            assert_eq!(prefixed, expected, "{scope}");
+        }
+    }
+
+    #[test]
+    fn test_range_keys() {
+        let dates = all_dates(&[2024]);
+        let mut ranges = Vec::new();
+        for (i, &start) in dates.iter().enumerate().step_by(7) {
+            for &end in dates[i..].iter().step_by(11) {
+                ranges.push(FuzzyDateRange::new(start, end).expect("expected valid test range"));
+            }
+        }
+        for range in &ranges {
+            assert_eq!(FuzzyDateRange::from_key(range.to_key()), Ok(*range));
+        }
+
+        let mut by_key = ranges.clone();
+        by_key.reverse();
+        by_key.sort_by_key(FuzzyDateRange::to_key);
+        ranges.sort();
+        assert_eq!(ranges, by_key);
+
+        let march = fuzzy_month(2024, 3);
+        let within = FuzzyDateRange::key_range_starting_within(&march);
+        let found: Vec<_> = by_key
+            .iter()
+            .filter(|range| within.contains(&range.to_key()))
+            .collect();
+        let expected: Vec<_> = by_key
+            .iter()
+            .filter(|range| march.contains(&range.start()))
+            .collect();
+        assert!(!expected.is_empty());
+        assert_eq!(found, expected);
+    }
+
+    #[test]
+    fn test_invalid_range_keys() {
+        let mut key = [0; 8];
+        key[..4].copy_from_slice(&fuzzy_year(2024).to_key());
+        key[4..].copy_from_slice(&[0x07, 0xE8, 1, 3]);
+        assert_eq!(
+            FuzzyDateRange::from_key(key),
+            Err(ByteRangeError::ParseError(ByteParseError::InvalidFormat(7)))
+        );
+
+        key[4..].copy_from_slice(&fuzzy_year(2020).to_key());
+        assert_eq!(
+            FuzzyDateRange::from_key(key),
+            Err(ByteRangeError::InvalidRange {
+                start: fuzzy_year(2024),
+                end: fuzzy_year(2020),
+            })
+        );
+    }
+}
@@ -0,0 +1,308 @@
+use core::fmt;
+
+use crate::{
+    Component, DATE_SEPARATOR, DateFormat, FuzzyDate, MONTH_FIRST_SEPARATOR, ParseError, Parser,
+    TwoDigitYears, diagnostics::field_text, prelude::*, types::days_in_month,
+};
+
+/// A single correction applied by [`Parser::parse_lenient`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Repair {
+    /// Whitespace inside the value was removed (`08 / 1991` → `08/1991`).
+    RemovedWhitespace,
+    /// A separator was replaced with the one the format expects (`2021/02/03` → `2021-02-03`).
+    ReplacedSeparator { from: char, to: char },
+    /// A day past the end of its month was clamped to the last day.
+    ClampedDay { from: u8, to: u8 },
+    /// An invalid day was dropped, leaving month precision.
+    DroppedDay(u8),
+    /// An invalid month was dropped along with any day, leaving year precision.
+    DroppedMonth(u8),
+}
+
+impl fmt::Display for Repair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::RemovedWhitespace => write!(f, "removed whitespace"),
+            Self::ReplacedSeparator { from, to } => write!(f, "replaced '{from}' with '{to}'"),
+            Self::ClampedDay { from, to } => write!(f, "clamped day {from} to {to}"),
+            Self::DroppedDay(day) => write!(f, "dropped invalid day {day}"),
+            Self::DroppedMonth(month) => write!(f, "dropped invalid month {month}"),
+        }
+    }
+}
+
+/// A date produced by lenient parsing, with the repairs needed to produce it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Repaired {
+    date: FuzzyDate,
+    repairs: Vec<Repair>,
+}
+
+impl Repaired {
+    /// The parsed date.
+    pub const fn date(&self) -> FuzzyDate {
+        self.date
+    }
+
+    /// The repairs applied, in the order they were made. Empty if the input
+    /// parsed strictly.
+    pub fn repairs(&self) -> &[Repair] {
+        &self.repairs
+    }
+
+    /// Returns `true` if no repairs were needed.
+    pub const fn is_exact(&self) -> bool {
+        self.repairs.is_empty()
+    }
+
+    /// Splits into the date and the repairs applied.
+    pub fn into_parts(self) -> (FuzzyDate, Vec<Repair>) {
+        (self.date, self.repairs)
+    }
+}
+
+impl Parser {
+    /// Parses `s`, repairing common defects instead of failing on them.
+    ///
+    /// Input that [`parse`](Self::parse) accepts is returned unchanged with no
+    /// repairs. Otherwise whitespace inside the value is removed, and for the
+    /// separated formats (ISO, month-first, day-first, dotted) any `-`, `/` or
+    /// `.` is replaced with the format's own separator. If the components are
+    /// then out of range, days are clamped and invalid components dropped as
+    /// configured by [`with_day_clamping`](Self::with_day_clamping) and
+    /// [`with_precision_fallback`](Self::with_precision_fallback). Every change
+    /// is recorded as a [`Repair`].
+    ///
+    /// # Errors
+    /// Returns the `ParseError` from parsing the repaired text if it still
+    /// cannot be parsed.
+    pub fn parse_lenient(&self, s: &str) -> Result<Repaired, ParseError> {
+        if let Ok(date) = self.parse(s) {
+            return Ok(Repaired {
+                date,
+                repairs: Vec::new(),
+            });
+        }
+
+        let mut repairs = Vec::new();
+        let text = normalize(self.format(), s.trim(), &mut repairs);
+
+        let date = match self.parse(&text) {
+            Ok(date) => date,
+            Err(ParseError::InvalidDay { year, month, day }) => {
+                self.repair_day(year, month, day, &mut repairs)?
+            }
+            Err(ParseError::InvalidMonth(month)) if self.falls_back_to_coarser_precision() => {
+                // Years are validated before months, so the year field is sound
+                let year_text = field_text(self.format(), &text, Component::Year)
+                    .ok_or(ParseError::InvalidMonth(month))?;
+                let year = match self.format() {
+                    DateFormat::MonthFirst | DateFormat::DayFirst | DateFormat::Dotted => {
+                        FuzzyDate::parse_year_field(year_text, self.two_digit_years())?
+                    }
+                    _ => FuzzyDate::parse_year_field(year_text.trim(), TwoDigitYears::Literal)?,
+                };
+                repairs.push(Repair::DroppedMonth(month));
+                FuzzyDate::from_columns(year, None, None)?
+            }
+            Err(err) => return Err(err),
+        };
+
+        Ok(Repaired { date, repairs })
+    }
+
+    /// Clamps or drops an out-of-range day according to this parser's settings.
+    fn repair_day(
+        self,
+        year: u16,
+        month: u8,
+        day: u8,
+        repairs: &mut Vec<Repair>,
+    ) -> Result<FuzzyDate, ParseError> {
+        let last = days_in_month(year, month);
+        if self.clamps_days() && day > last {
+            repairs.push(Repair::ClampedDay {
+                from: day,
+                to: last,
+            });
+            return FuzzyDate::from_columns(year, Some(month), Some(last));
+        }
+        if self.falls_back_to_coarser_precision() {
+            repairs.push(Repair::DroppedDay(day));
+            return FuzzyDate::from_columns(year, Some(month), None);
+        }
+        Err(ParseError::InvalidDay { month, day, year })
+    }
+}
+
+/// Applies the textual repairs for `format` to trimmed input.
+fn normalize(format: DateFormat, trimmed: &str, repairs: &mut Vec<Repair>) -> String {
+    let mut text = trimmed.to_owned();
+
+    // Month names are whitespace-separated, so spaces are significant there
+    if format != DateFormat::MonthName && text.contains(char::is_whitespace) {
+        text.retain(|c| !c.is_whitespace());
+        repairs.push(Repair::RemovedWhitespace);
+    }
+
+    let expected = match format {
+        DateFormat::Iso => DATE_SEPARATOR,
+        DateFormat::MonthFirst | DateFormat::DayFirst => MONTH_FIRST_SEPARATOR,
+        DateFormat::Dotted => '.',
+        DateFormat::Basic | DateFormat::MonthName => return text,
+    };
+    for from in ['-', '/', '.'] {
+        if from != expected && text.contains(from) {
+            text = text.replace(from, &expected.to_string());
+            repairs.push(Repair::ReplacedSeparator { from, to: expected });
+        }
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{fuzzy_day, fuzzy_month, fuzzy_year};
+
+    fn lenient(format: DateFormat) -> Parser {
+        Parser::new(format)
+            .with_day_clamping(true)
+            .with_precision_fallback(true)
+    }
+
+    fn repair(parser: Parser, input: &str) -> (FuzzyDate, Vec<Repair>) {
+        parser
+            .parse_lenient(input)
+            .expect("expected lenient parse to succeed")
+            .into_parts()
+    }
+
+    #[test]
+    fn test_strict_input_is_exact() {
+        let repaired = lenient(DateFormat::Iso)
+            .parse_lenient("2021-2-3")
+            .expect("expected valid input to parse");
+        assert!(repaired.is_exact());
+        assert_eq!(repaired.date(), fuzzy_day(2021, 2, 3));
+    }
+
+    #[test]
+    fn test_replaced_separator() {
+        let (date, repairs) = repair(Parser::new(DateFormat::Iso), "2021/02/03");
+        assert_eq!(date, fuzzy_day(2021, 2, 3));
+        assert_eq!(
+            repairs,
+            vec![Repair::ReplacedSeparator { from: '/', to: '-' }]
+        );
+    }
+
+    #[test]
+    fn test_removed_whitespace() {
+        let (date, repairs) = repair(Parser::new(DateFormat::MonthFirst), " 08 / 1991 ");
+        assert_eq!(date, fuzzy_month(1991, 8));
+        assert_eq!(repairs, vec![Repair::RemovedWhitespace]);
+    }
+
+    #[test]
+    fn test_clamped_day() {
+        let (date, repairs) = repair(lenient(DateFormat::MonthFirst), "02/30/2021");
+        assert_eq!(date, fuzzy_day(2021, 2, 28));
+        assert_eq!(repairs, vec![Repair::ClampedDay { from: 30, to: 28 }]);
+    }
+
+    #[test]
+    fn test_dropped_day() {
+        let parser = Parser::new(DateFormat::MonthFirst).with_precision_fallback(true);
+        let (date, repairs) = repair(parser, "02/30/2021");
+        assert_eq!(date, fuzzy_month(2021, 2));
+        assert_eq!(repairs, vec![Repair::DroppedDay(30)]);
+
+        // Day 0 cannot be clamped, so it is dropped even when clamping is on
+        let (date, repairs) = repair(lenient(DateFormat::Iso), "2021-02-00");
+        assert_eq!(date, fuzzy_month(2021, 2));
+        assert_eq!(repairs, vec![Repair::DroppedDay(0)]);
+    }
+
+    #[test]
+    fn test_dropped_month() {
+        let (date, repairs) = repair(lenient(DateFormat::Iso), "2021-13");
+        assert_eq!(date, fuzzy_year(2021));
+        assert_eq!(repairs, vec![Repair::DroppedMonth(13)]);
+
+        let (date, repairs) = repair(lenient(DateFormat::DayFirst), "01.13.2021");
+        assert_eq!(date, fuzzy_year(2021));
+        assert_eq!(
+            repairs,
+            vec![
+                Repair::ReplacedSeparator { from: '.', to: '/' },
+                Repair::DroppedMonth(13)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dropped_month_uses_two_digit_years() {
+        let parser =
+            lenient(DateFormat::MonthFirst).with_two_digit_years(TwoDigitYears::Pivot(1950));
+        let (date, repairs) = repair(parser, "13/01/91");
+        assert_eq!(date, fuzzy_year(1991));
+        assert_eq!(repairs, vec![Repair::DroppedMonth(13)]);
+    }
+
+    #[test]
+    fn test_repairs_are_opt_in() {
+        let parser = Parser::new(DateFormat::MonthFirst);
+        assert!(matches!(
+            parser.parse_lenient("02/30/2021"),
+            Err(ParseError::InvalidDay {
+                month: 2,
+                day: 30,
+                year: 2021
+            })
+        ));
+        assert_eq!(
+            Parser::new(DateFormat::Iso).parse_lenient("2021-13"),
+            Err(ParseError::InvalidMonth(13))
+        );
+    }
+
+    #[test]
+    fn test_unrepairable_input() {
+        let parser = lenient(DateFormat::Iso);
+        assert_eq!(parser.parse_lenient("   "), Err(ParseError::EmptyInput));
+        assert!(matches!(
+            parser.parse_lenient("not a date"),
+            Err(ParseError::InvalidFormat(_))
+        ));
+        assert_eq!(
+            parser.parse_lenient("0000-13"),
+            Err(ParseError::InvalidYear(0))
+        );
+    }
+
+    #[test]
+    fn test_month_name_keeps_spaces() {
+        let (date, repairs) = repair(lenient(DateFormat::MonthName), "Feb 30, 2024");
+        assert_eq!(date, fuzzy_day(2024, 2, 29));
+        assert_eq!(repairs, vec![Repair::ClampedDay { from: 30, to: 29 }]);
+    }
+
+    #[test]
+    fn test_repair_display() {
+        assert_eq!(
+            Repair::ReplacedSeparator { from: '/', to: '-' }.to_string(),
+            "replaced '/' with '-'"
+        );
+        assert_eq!(
+            Repair::ClampedDay { from: 31, to: 30 }.to_string(),
+            "clamped day 31 to 30"
+        );
+        assert_eq!(
+            Repair::DroppedMonth(13).to_string(),
+            "dropped invalid month 13"
+        );
+    }
+}
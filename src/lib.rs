@@ -1,26 +1,111 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![cfg_attr(
     not(test),
     deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)
 )]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "arrow")]
+mod arrow;
+#[cfg(feature = "alloc")]
+mod batch;
+mod bytes;
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+mod calendar;
+#[cfg(feature = "chrono")]
+mod chrono;
 mod consts;
+#[cfg(feature = "csv")]
+mod csv;
+#[cfg(feature = "alloc")]
+mod detect;
+#[cfg(feature = "alloc")]
+mod diagnostics;
+// Not `diesel`: derives from that crate resolve `diesel::` paths at the crate
+// root, where a module of the same name would shadow it.
+#[cfg(feature = "diesel")]
+mod diesel_impl;
+#[cfg(any(feature = "arbitrary", feature = "proptest", feature = "quickcheck"))]
+mod distribution;
+#[cfg(feature = "alloc")]
+mod format;
+#[cfg(feature = "jiff")]
+mod jiff;
+mod key;
+#[cfg(feature = "alloc")]
+mod lenient;
+mod macros;
+mod packed;
+#[cfg(feature = "polars")]
+mod polars;
 mod prelude;
+#[cfg(feature = "proptest")]
+mod proptest;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
 mod range;
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+#[cfg(feature = "schemars")]
+mod schemars;
+pub mod serde;
+#[cfg(feature = "rusqlite")]
+mod sql;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+#[cfg(feature = "time")]
+mod time;
 mod types;
 
-use std::{cmp::Ordering, convert::TryFrom, str::FromStr};
-
+use core::{
+    cmp::Ordering,
+    convert::TryFrom,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+#[cfg(feature = "alloc")]
+pub use batch::ParsedBatch;
+pub use bytes::{ByteParseError, ByteRangeError};
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+pub use calendar::{CalendarDate, ConversionError};
 pub use consts::*;
+#[cfg(feature = "csv")]
+pub use csv::{CsvColumn, CsvError, CsvNormalizer, CsvRowError, CsvSummary, FailureKind};
+#[cfg(feature = "alloc")]
+pub use detect::FormatDetector;
+#[cfg(feature = "alloc")]
+pub use diagnostics::{Component, Diagnostic};
+#[cfg(any(feature = "arbitrary", feature = "proptest", feature = "quickcheck"))]
+pub use distribution::DateDistribution;
+#[cfg(feature = "alloc")]
+pub use format::{DateFormat, Parser, TwoDigitYears};
+#[cfg(feature = "alloc")]
+pub use lenient::{Repair, Repaired};
+pub use packed::PackedFuzzyDate;
+#[cfg(feature = "polars")]
+pub use polars::FuzzyDateExpr;
 pub use range::{FuzzyDateRange, RangeError};
+#[cfg(feature = "rusqlite")]
+pub use sql::{SqlColumns, SqlRangeColumns};
 use types::days_in_month;
-pub use types::{Day, Month, Year};
+pub use types::{Day, Month, Precision, Year};
 
 use crate::prelude::*;
 
 /// Represents a date with varying levels of precision.
 /// This allows representing dates where only some components are known,
 /// without fabricating missing data.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(::diesel::expression::AsExpression),
+    diesel(sql_type = ::diesel::sql_types::Text)
+)]
 pub enum FuzzyDate {
     /// Full date with day, month, and year
     #[display(fmt = "{:04}-{:02}-{:02}", "year.get()", "month.get()", "day.get()")]
@@ -41,12 +126,21 @@ pub enum FuzzyDate {
 }
 
 /// Error type for parsing fuzzy dates from strings.
+///
+/// Non-exhaustive, as the `alloc` feature adds `InvalidFormat`.
 #[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
 pub enum ParseError {
     /// The input string format is invalid.
+    #[cfg(feature = "alloc")]
     #[error("Invalid date format: {0}")]
     InvalidFormat(String),
 
+    /// The input format is invalid at the given byte offset. Without the
+    /// `alloc` feature, `FromStr` reports every format error this way.
+    #[error("Invalid date format at byte {0}")]
+    InvalidFormatAt(usize),
+
     /// The year value is out of valid range (1..=9999).
     #[error("Invalid year: {0} (must be 1-{MAX_YEAR})")]
     InvalidYear(u16),
@@ -59,9 +153,19 @@ pub enum ParseError {
     #[error("Invalid day {day} for month {year}-{month:02}")]
     InvalidDay { month: u8, day: u8, year: u16 },
 
+    /// A day was given without the month it belongs to. Returned by
+    /// `from_columns` without the `alloc` feature, which otherwise reports an
+    /// `InvalidFormat` message.
+    #[error("Day {day} given without a month")]
+    DayWithoutMonth { day: u8 },
+
     /// The input string is empty.
     #[error("Empty date string")]
     EmptyInput,
+
+    /// A two-digit year was given to a parser configured to reject them.
+    #[error("Ambiguous two-digit year: {0:02}")]
+    TwoDigitYear(u8),
 }
 
 impl FuzzyDate {
@@ -166,11 +270,43 @@ impl FuzzyDate {
                 let year_nz = Self::validate_and_convert_year(year)?;
                 Ok(Self::Year { year: year_nz })
             }
+            #[cfg(feature = "alloc")]
             (None, Some(d)) => Err(ParseError::InvalidFormat(format!(
                 "Cannot have day {d} without month"
             ))),
+            #[cfg(not(feature = "alloc"))]
+            (None, Some(day)) => Err(ParseError::DayWithoutMonth { day }),
         }
     }
+
+    /// Converts to database columns as `i32`, for drivers without unsigned
+    /// integer types: (year, month, day)
+    pub fn to_integer_columns(&self) -> (i32, Option<i32>, Option<i32>) {
+        let (year, month, day) = self.to_columns();
+        (year.into(), month.map(Into::into), day.map(Into::into))
+    }
+
+    /// Creates from `i32` database columns: (year, month, day)
+    ///
+    /// # Errors
+    /// Returns `ParseError` if the year, month, or day values are invalid.
+    #[cfg(feature = "alloc")]
+    pub fn from_integer_columns(
+        year: i32,
+        month: Option<i32>,
+        day: Option<i32>,
+    ) -> Result<Self, ParseError> {
+        let narrow = |name: &str, value: i32| {
+            u8::try_from(value).map_err(|_| {
+                ParseError::InvalidFormat(format!("{name} column out of range: {value}"))
+            })
+        };
+        let year = u16::try_from(year)
+            .map_err(|_| ParseError::InvalidFormat(format!("Year column out of range: {year}")))?;
+        let month = month.map(|m| narrow("Month", m)).transpose()?;
+        let day = day.map(|d| narrow("Day", d)).transpose()?;
+        Self::from_columns(year, month, day)
+    }
 }
 
 // --- helpers for bounds / validation ---
@@ -198,6 +334,7 @@ fn next_day(year: u16, month: u8, day: u8) -> Option<(u16, u8, u8)> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl FromStr for FuzzyDate {
     type Err = ParseError;
 
@@ -217,29 +354,53 @@ impl FromStr for FuzzyDate {
             )));
         }
 
-        if has_hyphen {
-            // ISO format: YYYY or YYYY-MM or YYYY-MM-DD
-            let parts: Vec<&str> = trimmed.split(DATE_SEPARATOR).map(str::trim).collect();
-            match parts.len() {
-                1 => Self::parse_year_only(parts[0]),
-                2 => Self::parse_iso_month_year(&parts),
-                3 => Self::parse_iso_full_date(&parts),
-                _ => Err(ParseError::InvalidFormat(format!(
-                    "Too many {} separators: expected 0-2, found {}",
-                    DATE_SEPARATOR,
-                    parts.len() - 1
-                ))),
-            }
-        } else if has_slash {
+        if has_slash {
             // Month-first format: MM/YYYY or MM/DD/YYYY
             Self::parse_slash_date(trimmed)
         } else {
-            // No delimiter, bare year
-            Self::parse_year_only(trimmed)
+            // ISO format: YYYY or YYYY-MM or YYYY-MM-DD
+            Self::parse_iso(trimmed)
         }
     }
 }
 
+/// Without `alloc`, parsing goes through the allocation-free byte parser.
+/// Offsets in `InvalidFormatAt` are relative to `s`.
+#[cfg(not(feature = "alloc"))]
+impl FromStr for FuzzyDate {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let offset = s.len() - s.trim_start().len();
+        Self::parse_bytes(s.trim().as_bytes()).map_err(|err| match err {
+            ByteParseError::InvalidFormat(at) => ParseError::InvalidFormatAt(offset + at),
+            err => err.into(),
+        })
+    }
+}
+
+impl FuzzyDate {
+    /// Validates and creates a Year type
+    const fn validate_and_convert_year(year: u16) -> Result<types::Year, ParseError> {
+        types::Year::new(year)
+    }
+
+    /// Validates and creates a Month type
+    const fn validate_and_convert_month(month: u8) -> Result<types::Month, ParseError> {
+        types::Month::new(month)
+    }
+
+    /// Validates and creates a Day type
+    const fn validate_and_convert_day(
+        year: u16,
+        month: u8,
+        day: u8,
+    ) -> Result<types::Day, ParseError> {
+        types::Day::new(day, year, month)
+    }
+}
+
+#[cfg(feature = "alloc")]
 impl FuzzyDate {
     /// Helper to parse u16 with better error messages
     fn parse_u16(s: &str) -> Result<u16, ParseError> {
@@ -253,19 +414,29 @@ impl FuzzyDate {
             .map_err(|_| ParseError::InvalidFormat(s.to_owned()))
     }
 
-    /// Validates and creates a Year type
-    fn validate_and_convert_year(year: u16) -> Result<types::Year, ParseError> {
-        types::Year::new(year)
-    }
-
-    /// Validates and creates a Month type
-    fn validate_and_convert_month(month: u8) -> Result<types::Month, ParseError> {
-        types::Month::new(month)
+    /// Parses a year field, resolving exactly two digits according to `years`
+    pub(crate) fn parse_year_field(s: &str, years: TwoDigitYears) -> Result<u16, ParseError> {
+        if s.len() == 2 {
+            years.resolve(Self::parse_u8(s)?)
+        } else {
+            Self::parse_u16(s)
+        }
     }
 
-    /// Validates and creates a Day type
-    fn validate_and_convert_day(year: u16, month: u8, day: u8) -> Result<types::Day, ParseError> {
-        types::Day::new(day, year, month)
+    /// Parse an ISO 8601 extended date (YYYY, YYYY-MM or YYYY-MM-DD).
+    /// Expects input that has already been trimmed.
+    pub(crate) fn parse_iso(s: &str) -> Result<Self, ParseError> {
+        let parts: Vec<&str> = s.split(DATE_SEPARATOR).map(str::trim).collect();
+        match parts.len() {
+            1 => Self::parse_year_only(parts[0]),
+            2 => Self::parse_iso_month_year(&parts),
+            3 => Self::parse_iso_full_date(&parts),
+            _ => Err(ParseError::InvalidFormat(format!(
+                "Too many {} separators: expected 0-2, found {}",
+                DATE_SEPARATOR,
+                parts.len() - 1
+            ))),
+        }
     }
 
     fn parse_iso_month_year(parts: &[&str]) -> Result<Self, ParseError> {
@@ -310,36 +481,48 @@ impl FuzzyDate {
         Ok(Self::Year { year })
     }
 
-    /// Parse a slash-delimited date (MM/YYYY or MM/DD/YYYY) without heap allocation.
+    /// Parse a slash-delimited, month-first date (MM/YYYY or MM/DD/YYYY).
+    fn parse_slash_date(s: &str) -> Result<Self, ParseError> {
+        Self::parse_separated(s, b'/', FieldOrder::MonthFirst, TwoDigitYears::Literal)
+    }
+
+    /// Parse a two- or three-field date (MM?YYYY, or MM?DD?YYYY / DD?MM?YYYY
+    /// depending on `order`) delimited by `sep`, without heap allocation.
     ///
     /// Uses a strict byte-by-byte walk: month and day fields are at most 2 ASCII
     /// digits; any other byte in those positions is an immediate error.  The year
-    /// field consumes all remaining bytes, which must all be ASCII digits.
-    fn parse_slash_date(s: &str) -> Result<Self, ParseError> {
+    /// field consumes all remaining bytes, which must all be ASCII digits, and a
+    /// two-digit year is resolved according to `years`.
+    pub(crate) fn parse_separated(
+        s: &str,
+        sep: u8,
+        order: FieldOrder,
+        years: TwoDigitYears,
+    ) -> Result<Self, ParseError> {
         let b = s.as_bytes();
         let err = || ParseError::InvalidFormat(s.to_string());
         let mut pos = 0;
 
-        // --- Month: 1-2 ASCII digits, must be followed by '/' ---
+        // --- First field: 1-2 ASCII digits, must be followed by the separator ---
         if pos >= b.len() || !b[pos].is_ascii_digit() {
             return Err(err());
         }
         pos += 1;
 
         if pos < b.len() && b[pos].is_ascii_digit() {
-            // Two-digit month: the next byte must be '/'.
+            // Two-digit field: the next byte must be the separator.
             pos += 1;
-            if pos >= b.len() || b[pos] != b'/' {
+            if pos >= b.len() || b[pos] != sep {
                 return Err(err());
             }
-        } else if pos >= b.len() || b[pos] != b'/' {
+        } else if pos >= b.len() || b[pos] != sep {
             return Err(err());
         }
 
-        let month_str = &s[..pos];
-        pos += 1; // skip '/'
+        let first_str = &s[..pos];
+        pos += 1; // skip separator
 
-        // --- Second field: 1-2 digit day followed by '/', or year to end-of-string ---
+        // --- Second field: 1-2 digits followed by the separator, or year to end-of-string ---
         if pos >= b.len() || !b[pos].is_ascii_digit() {
             return Err(err());
         }
@@ -356,31 +539,23 @@ impl FuzzyDate {
                     }
                     pos += 1;
                 }
-                let month_u8 = Self::parse_u8(month_str)?;
-                let year_u16 = Self::parse_u16(&s[field2_start..])?;
-                let month = Self::validate_and_convert_month(month_u8)?;
-                let year = Self::validate_and_convert_year(year_u16)?;
-                return Ok(Self::Month { year, month });
+                return Self::month_year_from_fields(first_str, &s[field2_start..], years);
             }
         }
 
         // pos is now past exactly 1 or 2 digits of the second field.
         if pos == b.len() {
             // No more input: the second field is the year.
-            let month_u8 = Self::parse_u8(month_str)?;
-            let year_u16 = Self::parse_u16(&s[field2_start..])?;
-            let month = Self::validate_and_convert_month(month_u8)?;
-            let year = Self::validate_and_convert_year(year_u16)?;
-            return Ok(Self::Month { year, month });
+            return Self::month_year_from_fields(first_str, &s[field2_start..], years);
         }
 
-        if b[pos] != b'/' {
+        if b[pos] != sep {
             return Err(err());
         }
 
-        // Second field is a 1-2 digit day; what follows is the year.
-        let day_str = &s[field2_start..pos];
-        pos += 1; // skip '/'
+        // Second field is a 1-2 digit day or month; what follows is the year.
+        let second_str = &s[field2_start..pos];
+        pos += 1; // skip separator
 
         // --- Year: all remaining bytes must be ASCII digits ---
         let year_start = pos;
@@ -394,14 +569,42 @@ impl FuzzyDate {
             pos += 1;
         }
 
+        let (month_str, day_str) = match order {
+            FieldOrder::MonthFirst => (first_str, second_str),
+            FieldOrder::DayFirst => (second_str, first_str),
+        };
         let month_u8 = Self::parse_u8(month_str)?;
         let day_u8 = Self::parse_u8(day_str)?;
-        let year_u16 = Self::parse_u16(&s[year_start..])?;
+        let year_u16 = Self::parse_year_field(&s[year_start..], years)?;
         let year = Self::validate_and_convert_year(year_u16)?;
         let month = Self::validate_and_convert_month(month_u8)?;
         let day = Self::validate_and_convert_day(year_u16, month_u8, day_u8)?;
         Ok(Self::Day { year, month, day })
     }
+
+    /// Builds a month-precision date from already delimited month and year fields.
+    fn month_year_from_fields(
+        month_str: &str,
+        year_str: &str,
+        years: TwoDigitYears,
+    ) -> Result<Self, ParseError> {
+        let month_u8 = Self::parse_u8(month_str)?;
+        let year_u16 = Self::parse_year_field(year_str, years)?;
+        let month = Self::validate_and_convert_month(month_u8)?;
+        let year = Self::validate_and_convert_year(year_u16)?;
+        Ok(Self::Month { year, month })
+    }
+}
+
+/// Order of the day and month fields in a three-field separated date.
+/// Two-field dates are always month then year.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FieldOrder {
+    /// MM/DD/YYYY
+    MonthFirst,
+    /// DD/MM/YYYY
+    DayFirst,
 }
 
 impl FuzzyDate {
@@ -446,6 +649,28 @@ impl FuzzyDate {
         }
     }
 
+    /// The precision this value was recorded at.
+    pub const fn precision(&self) -> Precision {
+        match self {
+            Self::Year { .. } => Precision::Year,
+            Self::Month { .. } => Precision::Month,
+            Self::Day { .. } => Precision::Day,
+        }
+    }
+
+    /// Drops components finer than `precision`. A value that is already at
+    /// or coarser than `precision` is returned unchanged.
+    #[must_use]
+    pub const fn truncate(self, precision: Precision) -> Self {
+        match (self, precision) {
+            (Self::Day { year, .. } | Self::Month { year, .. }, Precision::Year) => {
+                Self::Year { year }
+            }
+            (Self::Day { year, month, .. }, Precision::Month) => Self::Month { year, month },
+            _ => self,
+        }
+    }
+
     /// Rank used for ordering ties on the same `lower_bound`:
     /// less precise comes first: Year < Month < Day.
     #[inline]
@@ -479,6 +704,14 @@ impl Ord for FuzzyDate {
     }
 }
 
+/// Hashes the packed bits, so a `FuzzyDate` and its `PackedFuzzyDate` hash
+/// identically.
+impl Hash for FuzzyDate {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        PackedFuzzyDate::new(*self).hash(state);
+    }
+}
+
 impl TryFrom<(u16, Option<u8>, Option<u8>)> for FuzzyDate {
     type Error = ParseError;
 
@@ -487,24 +720,10 @@ impl TryFrom<(u16, Option<u8>, Option<u8>)> for FuzzyDate {
     }
 }
 
-impl serde::Serialize for FuzzyDate {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        serializer.serialize_str(&self.to_string())
-    }
-}
-
-impl<'de> serde::Deserialize<'de> for FuzzyDate {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        let s = String::deserialize(deserializer)?;
-        s.parse().map_err(serde::de::Error::custom)
-    }
-}
+/// Inputs shared with the integration tests.
+#[cfg(all(test, feature = "alloc"))]
+#[path = "../tests/common/mod.rs"]
+mod test_cases;
 
 #[cfg(test)]
 pub(crate) mod test_utils {
@@ -579,7 +798,8 @@ pub(crate) mod test_utils {
 mod tests {
     use super::*;
     use crate::test_utils::{
-        day, from_columns, from_json, from_tuple, month, parse_date, to_json, year,
+        day, from_columns, from_json, from_tuple, fuzzy_day, fuzzy_month, fuzzy_year, month,
+        parse_date, to_json, year,
     };
 
     #[test]
@@ -938,6 +1158,35 @@ mod tests {
         // Invalid: day without month
         let result = FuzzyDate::from_columns(1991, None, Some(15));
         assert!(result.is_err());
+        #[cfg(not(feature = "alloc"))]
+        assert_eq!(result, Err(ParseError::DayWithoutMonth { day: 15 }));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_integer_columns() {
+        let date = fuzzy_month(1991, 8);
+        assert_eq!(date.to_integer_columns(), (1991, Some(8), None));
+        assert_eq!(
+            FuzzyDate::from_integer_columns(1991, Some(8), None),
+            Ok(date)
+        );
+
+        assert_eq!(
+            FuzzyDate::from_integer_columns(1991, Some(13), None),
+            Err(ParseError::InvalidMonth(13))
+        );
+        for (y, m, d) in [
+            (-1, None, None),
+            (70_000, None, None),
+            (1991, Some(-8), None),
+            (1991, Some(8), Some(256)),
+        ] {
+            assert!(matches!(
+                FuzzyDate::from_integer_columns(y, m, d),
+                Err(ParseError::InvalidFormat(_))
+            ));
+        }
     }
 
     #[test]
@@ -973,6 +1222,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn test_bad_tokens() {
         // Non-numeric year
         let result = "199A".parse::<FuzzyDate>();
@@ -987,6 +1237,24 @@ mod tests {
         assert!(matches!(result, Err(ParseError::InvalidFormat(_))));
     }
 
+    #[test]
+    #[cfg(not(feature = "alloc"))]
+    fn test_from_str_offsets() {
+        assert_eq!(
+            "199A".parse::<FuzzyDate>(),
+            Err(ParseError::InvalidFormatAt(3))
+        );
+        // Offsets count the leading whitespace that parsing trims
+        assert_eq!(
+            "  1991-08-XX".parse::<FuzzyDate>(),
+            Err(ParseError::InvalidFormatAt(10))
+        );
+        assert_eq!(
+            " 1991-13".parse::<FuzzyDate>(),
+            Err(ParseError::InvalidMonth(13))
+        );
+    }
+
     #[test]
     fn test_ordering_across_boundaries() {
         let jan31 = FuzzyDate::Day {
@@ -1080,6 +1348,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn test_too_many_date_separators() {
         // Too many hyphens in ISO format
         let result = "2000-01-15-23".parse::<FuzzyDate>();
@@ -1094,12 +1363,26 @@ mod tests {
         // Too many slashes in month-first format
         assert!("01/15/2000/extra".parse::<FuzzyDate>().is_err());
     }
+
+    #[test]
+    fn test_precision_and_truncate() {
+        let date = fuzzy_day(2024, 2, 29);
+        assert_eq!(date.precision(), Precision::Day);
+        assert_eq!(date.truncate(Precision::Month), fuzzy_month(2024, 2));
+        assert_eq!(date.truncate(Precision::Year), fuzzy_year(2024));
+        assert_eq!(date.truncate(Precision::Day), date);
+
+        // Truncating never adds detail
+        assert_eq!(fuzzy_year(2024).truncate(Precision::Day), fuzzy_year(2024));
+        assert!(Precision::Year < Precision::Month && Precision::Month < Precision::Day);
+    }
 }
 
 #[cfg(doctest)]
 #[doc = include_str!("../README.md")]
 pub struct ReadmeDoctests;
 
-#[cfg(doctest)]
+/// The examples use the string parsers, which need `alloc`.
+#[cfg(all(doctest, feature = "alloc"))]
 #[doc = include_str!("../docs/examples.md")]
 pub struct ExamplesDoctests;
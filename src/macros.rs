@@ -0,0 +1,121 @@
+/// Builds a [`FuzzyDate`](crate::FuzzyDate) constant from a string literal,
+/// checked at compile time.
+///
+/// Accepts what [`FuzzyDate::parse_bytes`](crate::FuzzyDate::parse_bytes)
+/// accepts. The result is a constant, so the macro can initialize `const` and
+/// `static` items.
+///
+/// ```
+/// use fuzzy_date::{FuzzyDate, fuzzy_date};
+///
+/// const RELEASE: FuzzyDate = fuzzy_date!("2026-02");
+/// assert_eq!(RELEASE, "2026-02".parse()?);
+/// assert_eq!(fuzzy_date!("02/13/2026").to_string(), "2026-02-13");
+/// # Ok::<(), fuzzy_date::ParseError>(())
+/// ```
+///
+/// An invalid literal is a build error:
+///
+/// ```compile_fail
+/// let date = fuzzy_date::fuzzy_date!("2026-02-30");
+/// ```
+#[macro_export]
+macro_rules! fuzzy_date {
+    ($input:literal) => {{
+        const DATE: $crate::FuzzyDate =
+            match $crate::FuzzyDate::parse_bytes(::core::primitive::str::as_bytes($input)) {
+                ::core::result::Result::Ok(date) => date,
+                ::core::result::Result::Err($crate::ByteParseError::InvalidYear(_)) => {
+                    ::core::panic!(::core::concat!("invalid year in fuzzy date: ", $input))
+                }
+                ::core::result::Result::Err($crate::ByteParseError::InvalidMonth(_)) => {
+                    ::core::panic!(::core::concat!("invalid month in fuzzy date: ", $input))
+                }
+                ::core::result::Result::Err($crate::ByteParseError::InvalidDay { .. }) => {
+                    ::core::panic!(::core::concat!("invalid day in fuzzy date: ", $input))
+                }
+                ::core::result::Result::Err(_) => {
+                    ::core::panic!(::core::concat!("invalid fuzzy date: ", $input))
+                }
+            };
+        DATE
+    }};
+}
+
+/// Builds a [`FuzzyDateRange`](crate::FuzzyDateRange) constant from a
+/// `start/end` string literal, checked at compile time.
+///
+/// Accepts what
+/// [`FuzzyDateRange::parse_bytes`](crate::FuzzyDateRange::parse_bytes)
+/// accepts.
+///
+/// ```
+/// use fuzzy_date::{FuzzyDateRange, fuzzy_date, fuzzy_range};
+///
+/// const TENURE: FuzzyDateRange = fuzzy_range!("2020/2026-02-13");
+/// assert_eq!(TENURE.start(), fuzzy_date!("2020"));
+/// assert!(TENURE.contains(&fuzzy_date!("2024-06")));
+/// ```
+///
+/// A malformed literal, or one whose start is after its end, is a build error:
+///
+/// ```compile_fail
+/// let range = fuzzy_date::fuzzy_range!("2026/2020");
+/// ```
+#[macro_export]
+macro_rules! fuzzy_range {
+    ($input:literal) => {{
+        const RANGE: $crate::FuzzyDateRange =
+            match $crate::FuzzyDateRange::parse_bytes(::core::primitive::str::as_bytes($input)) {
+                ::core::result::Result::Ok(range) => range,
+                ::core::result::Result::Err($crate::ByteRangeError::InvalidRange { .. }) => {
+                    ::core::panic!(::core::concat!(
+                        "fuzzy date range starts after it ends: ",
+                        $input
+                    ))
+                }
+                ::core::result::Result::Err(_) => {
+                    ::core::panic!(::core::concat!("invalid fuzzy date range: ", $input))
+                }
+            };
+        RANGE
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        FuzzyDate, FuzzyDateRange,
+        test_utils::{fuzzy_day, fuzzy_month, fuzzy_year},
+    };
+
+    const DATE: FuzzyDate = fuzzy_date!(" 2026-02 ");
+    static RANGE: FuzzyDateRange = fuzzy_range!("1991-08/2026-02-13");
+
+    #[test]
+    fn test_fuzzy_date() {
+        assert_eq!(DATE, fuzzy_month(2026, 2));
+        assert_eq!(fuzzy_date!("2026"), fuzzy_year(2026));
+        assert_eq!(fuzzy_date!("2024-02-29"), fuzzy_day(2024, 2, 29));
+        assert_eq!(fuzzy_date!("08/15/1991"), fuzzy_day(1991, 8, 15));
+    }
+
+    #[test]
+    fn test_fuzzy_range() {
+        assert_eq!(
+            RANGE.dates(),
+            (fuzzy_month(1991, 8), fuzzy_day(2026, 2, 13))
+        );
+        assert_eq!(fuzzy_range!("2020/2020").start(), fuzzy_year(2020));
+    }
+
+    #[test]
+    fn test_const_constructors() {
+        const YEAR: Result<crate::Year, crate::ParseError> = crate::Year::new(0);
+        const MONTH: Result<crate::Month, crate::ParseError> = crate::Month::new(12);
+        const DAY: Result<crate::Day, crate::ParseError> = crate::Day::new(29, 2023, 2);
+        assert_eq!(YEAR, Err(crate::ParseError::InvalidYear(0)));
+        assert_eq!(MONTH.map(crate::Month::get), Ok(12));
+        assert!(DAY.is_err());
+    }
+}
@@ -0,0 +1,146 @@
+use core::{fmt, num::NonZeroU32};
+
+use crate::{ByteParseError, FuzzyDate};
+
+/// A `FuzzyDate` packed into four bytes.
+///
+/// The value is the big-endian reading of [`FuzzyDate::to_key`], which is
+/// never zero, so `Option<PackedFuzzyDate>` is also four bytes. Integer order
+/// matches `FuzzyDate`'s `Ord`, and both types hash the same way.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct PackedFuzzyDate(NonZeroU32);
+
+impl PackedFuzzyDate {
+    /// Packs a date.
+    pub const fn new(date: FuzzyDate) -> Self {
+        match NonZeroU32::new(u32::from_be_bytes(date.to_key())) {
+            Some(bits) => Self(bits),
+            None => unreachable!(),
+        }
+    }
+
+    /// Unpacks the date.
+    pub fn get(self) -> FuzzyDate {
+        FuzzyDate::from_key(self.0.get().to_be_bytes())
+            .unwrap_or_else(|_| unreachable!("packed bits are always a valid key"))
+    }
+
+    /// The packed bits, for storage.
+    pub const fn to_bits(self) -> u32 {
+        self.0.get()
+    }
+
+    /// Restores a value from [`to_bits`](Self::to_bits).
+    ///
+    /// # Errors
+    /// Returns the `ByteParseError` from [`FuzzyDate::from_key`] for bits that
+    /// are not a valid key.
+    pub fn from_bits(bits: u32) -> Result<Self, ByteParseError> {
+        FuzzyDate::from_key(bits.to_be_bytes()).map(Self::new)
+    }
+}
+
+impl From<FuzzyDate> for PackedFuzzyDate {
+    fn from(date: FuzzyDate) -> Self {
+        Self::new(date)
+    }
+}
+
+impl From<PackedFuzzyDate> for FuzzyDate {
+    fn from(packed: PackedFuzzyDate) -> Self {
+        packed.get()
+    }
+}
+
+impl fmt::Debug for PackedFuzzyDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PackedFuzzyDate").field(&self.get()).finish()
+    }
+}
+
+impl fmt::Display for PackedFuzzyDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.get().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{BuildHasher, BuildHasherDefault},
+        mem::size_of,
+    };
+
+    use super::*;
+    use crate::test_utils::{fuzzy_day, fuzzy_month, fuzzy_year};
+
+    fn sample() -> Vec<FuzzyDate> {
+        let mut dates = vec![fuzzy_year(1), fuzzy_day(9999, 12, 31)];
+        for year in [1900, 2000, 2024] {
+            dates.push(fuzzy_year(year));
+            for month in 1..=12 {
+                dates.push(fuzzy_month(year, month));
+                for day in 1..=crate::types::days_in_month(year, month) {
+                    dates.push(fuzzy_day(year, month, day));
+                }
+            }
+        }
+        dates
+    }
+
+    #[test]
+    fn test_size() {
+        assert_eq!(size_of::<PackedFuzzyDate>(), 4);
+        assert_eq!(size_of::<Option<PackedFuzzyDate>>(), 4);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for date in sample() {
+            let packed = PackedFuzzyDate::from(date);
+            assert_eq!(FuzzyDate::from(packed), date);
+            assert_eq!(PackedFuzzyDate::from_bits(packed.to_bits()), Ok(packed));
+            assert_eq!(packed.to_string(), date.to_string());
+        }
+        assert_eq!(
+            PackedFuzzyDate::from_bits(0),
+            Err(ByteParseError::InvalidYear(0))
+        );
+    }
+
+    #[test]
+    fn test_ord_matches() {
+        let mut dates = sample();
+        dates.reverse();
+        let mut packed: Vec<PackedFuzzyDate> = dates.iter().copied().map(Into::into).collect();
+        dates.sort();
+        packed.sort();
+        let unpacked: Vec<FuzzyDate> = packed.into_iter().map(Into::into).collect();
+        assert_eq!(unpacked, dates);
+    }
+
+    #[test]
+    fn test_hash_matches() {
+        let hasher = BuildHasherDefault::<DefaultHasher>::default();
+        for date in sample() {
+            assert_eq!(
+                hasher.hash_one(date),
+                hasher.hash_one(PackedFuzzyDate::new(date))
+            );
+        }
+        assert_ne!(
+            hasher.hash_one(fuzzy_year(2024)),
+            hasher.hash_one(fuzzy_month(2024, 1))
+        );
+    }
+
+    #[test]
+    fn test_debug() {
+        assert_eq!(
+            format!("{:?}", PackedFuzzyDate::new(fuzzy_month(2024, 2))),
+            "PackedFuzzyDate(Month { year: Year(2024), month: Month(2) })"
+        );
+    }
+}
@@ -0,0 +1,637 @@
+//! Polars integration.
+//!
+//! [`FuzzyDateExpr`] adds expressions over fuzzy date columns to `Expr`. A
+//! date column is a struct of `year: UInt16`, `month: UInt8` and `day: UInt8`,
+//! matching the `arrow` feature's layout, and a range column is a struct of
+//! `start` and `end` date structs. Integer struct fields of other widths are
+//! cast on read.
+
+use ::polars::prelude::{
+    BooleanChunked, Column, DataType, Expr, Field, Int32Chunked, IntoColumn, IntoSeries,
+    NewChunkedArray, PlSmallStr, PolarsError, PolarsResult, Schema, Series, StringChunked,
+    StructChunked, UInt8Chunked, UInt16Chunked,
+};
+use polars_arrow::bitmap::Bitmap;
+
+use crate::{FuzzyDate, FuzzyDateRange, Precision};
+
+fn date_dtype() -> DataType {
+    DataType::Struct(vec![
+        Field::new("year".into(), DataType::UInt16),
+        Field::new("month".into(), DataType::UInt8),
+        Field::new("day".into(), DataType::UInt8),
+    ])
+}
+
+fn range_dtype() -> DataType {
+    DataType::Struct(vec![
+        Field::new("start".into(), date_dtype()),
+        Field::new("end".into(), date_dtype()),
+    ])
+}
+
+fn compute_error(message: String) -> PolarsError {
+    PolarsError::ComputeError(message.into())
+}
+
+/// Days from 1970-01-01 to the given proleptic Gregorian date.
+fn days_since_epoch((year, month, day): (u16, u8, u8)) -> i32 {
+    let year = i32::from(year) - i32::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = i32::from(month);
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i32::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The values of a date or range column.
+enum Values {
+    Dates(Vec<Option<FuzzyDate>>),
+    Ranges(Vec<Option<FuzzyDateRange>>),
+}
+
+impl Values {
+    fn read(series: &Series) -> PolarsResult<Self> {
+        let DataType::Struct(fields) = series.dtype() else {
+            return Err(compute_error(format!(
+                "expected a fuzzy date or range struct, found {}",
+                series.dtype()
+            )));
+        };
+        if fields.iter().any(|field| field.name() == "start") {
+            read_ranges(series).map(Self::Ranges)
+        } else {
+            read_dates(series).map(Self::Dates)
+        }
+    }
+
+    const fn len(&self) -> usize {
+        match self {
+            Self::Dates(dates) => dates.len(),
+            Self::Ranges(ranges) => ranges.len(),
+        }
+    }
+
+    /// The earliest and latest dates of `row`, broadcasting a single value.
+    fn bounds(&self, row: usize) -> Option<(FuzzyDate, FuzzyDate)> {
+        match self {
+            Self::Dates(dates) => broadcast(dates, row).map(|date| (date, date)),
+            Self::Ranges(ranges) => broadcast(ranges, row).map(|range| range.dates()),
+        }
+    }
+}
+
+fn broadcast<T: Copy>(values: &[Option<T>], row: usize) -> Option<T> {
+    if values.len() == 1 {
+        values[0]
+    } else {
+        values.get(row).copied().flatten()
+    }
+}
+
+fn read_dates(series: &Series) -> PolarsResult<Vec<Option<FuzzyDate>>> {
+    let fields = series.struct_()?;
+    let years = fields.field_by_name("year")?.cast(&DataType::UInt16)?;
+    let months = fields.field_by_name("month")?.cast(&DataType::UInt8)?;
+    let days = fields.field_by_name("day")?.cast(&DataType::UInt8)?;
+    let nulls = series.is_null();
+
+    nulls
+        .iter()
+        .zip(years.u16()?.iter())
+        .zip(months.u8()?.iter().zip(days.u8()?.iter()))
+        .enumerate()
+        .map(|(row, ((null, year), (month, day)))| {
+            if null == Some(true) {
+                return Ok(None);
+            }
+            let year = year.ok_or_else(|| compute_error(format!("row {row}: null year")))?;
+            FuzzyDate::from_columns(year, month, day)
+                .map(Some)
+                .map_err(|err| compute_error(format!("row {row}: {err}")))
+        })
+        .collect()
+}
+
+fn read_ranges(series: &Series) -> PolarsResult<Vec<Option<FuzzyDateRange>>> {
+    let fields = series.struct_()?;
+    let starts = read_dates(&fields.field_by_name("start")?)?;
+    let ends = read_dates(&fields.field_by_name("end")?)?;
+    let nulls = series.is_null();
+
+    nulls
+        .iter()
+        .zip(starts.into_iter().zip(ends))
+        .enumerate()
+        .map(|(row, (null, dates))| match dates {
+            _ if null == Some(true) => Ok(None),
+            (Some(start), Some(end)) => FuzzyDateRange::new(start, end)
+                .map(Some)
+                .map_err(|err| compute_error(format!("row {row}: {err}"))),
+            _ => Err(compute_error(format!("row {row}: null start or end"))),
+        })
+        .collect()
+}
+
+fn struct_series(
+    name: PlSmallStr,
+    fields: &[Series],
+    valid: impl Iterator<Item = bool>,
+) -> PolarsResult<Series> {
+    let len = fields.first().map_or(0, |field| field.len());
+    let validity: Bitmap = valid.collect();
+    let validity = (validity.unset_bits() > 0).then_some(validity);
+    Ok(StructChunked::from_series(name, len, fields.iter())?
+        .with_outer_validity(validity)
+        .into_series())
+}
+
+fn write_dates(name: PlSmallStr, dates: &[Option<FuzzyDate>]) -> PolarsResult<Series> {
+    let columns = || dates.iter().map(|date| date.map(|date| date.to_columns()));
+    let fields = [
+        UInt16Chunked::from_iter_options("year".into(), columns().map(|c| c.map(|c| c.0)))
+            .into_series(),
+        UInt8Chunked::from_iter_options("month".into(), columns().map(|c| c.and_then(|c| c.1)))
+            .into_series(),
+        UInt8Chunked::from_iter_options("day".into(), columns().map(|c| c.and_then(|c| c.2)))
+            .into_series(),
+    ];
+    struct_series(name, &fields, dates.iter().map(Option::is_some))
+}
+
+fn write_ranges(name: PlSmallStr, ranges: &[Option<FuzzyDateRange>]) -> PolarsResult<Series> {
+    let starts: Vec<_> = ranges.iter().map(|r| r.map(|r| r.start())).collect();
+    let ends: Vec<_> = ranges.iter().map(|r| r.map(|r| r.end())).collect();
+    let fields = [
+        write_dates("start".into(), &starts)?,
+        write_dates("end".into(), &ends)?,
+    ];
+    struct_series(name, &fields, ranges.iter().map(Option::is_some))
+}
+
+fn write_values(name: PlSmallStr, values: &Values) -> PolarsResult<Series> {
+    match values {
+        Values::Dates(dates) => write_dates(name, dates),
+        Values::Ranges(ranges) => write_ranges(name, ranges),
+    }
+}
+
+/// Parses every non-null string with `parse`, failing on the first invalid
+/// value when `strict` and producing null for it otherwise.
+fn parse_strings<T, E: std::fmt::Display>(
+    column: &Column,
+    strict: bool,
+    parse: impl Fn(&str) -> Result<T, E>,
+) -> PolarsResult<Vec<Option<T>>> {
+    column
+        .as_materialized_series()
+        .str()?
+        .iter()
+        .enumerate()
+        .map(|(row, value)| match value.map(&parse) {
+            Some(Err(err)) if strict => Err(compute_error(format!("row {row}: {err}"))),
+            Some(Err(_)) | None => Ok(None),
+            Some(Ok(value)) => Ok(Some(value)),
+        })
+        .collect()
+}
+
+fn with_dtype(dtype: fn() -> DataType) -> impl Fn(&Schema, &Field) -> PolarsResult<Field> {
+    move |_, field| Ok(Field::new(field.name().clone(), dtype()))
+}
+
+fn same_dtype() -> impl Fn(&Schema, &Field) -> PolarsResult<Field> {
+    |_, field| Ok(field.clone())
+}
+
+fn boolean_of_first() -> impl Fn(&Schema, &[Field]) -> PolarsResult<Field> {
+    |_, fields| Ok(Field::new(fields[0].name().clone(), DataType::Boolean))
+}
+
+/// Expressions over fuzzy date and range struct columns.
+pub trait FuzzyDateExpr {
+    /// Parses a string column into a date struct column. Invalid values are
+    /// an error when `strict`, and null otherwise.
+    fn fuzzy_parse(self, strict: bool) -> Expr;
+
+    /// Parses a string column into a range struct column. Invalid values are
+    /// an error when `strict`, and null otherwise.
+    fn fuzzy_parse_range(self, strict: bool) -> Expr;
+
+    /// Earliest concrete day of each date or range, as a `Date`.
+    fn fuzzy_lower_bound(self) -> Expr;
+
+    /// Latest concrete day of each date or range (inclusive), as a `Date`.
+    fn fuzzy_upper_bound(self) -> Expr;
+
+    /// Precision of each date as `"year"`, `"month"` or `"day"`.
+    fn fuzzy_precision(self) -> Expr;
+
+    /// Truncates each date, or both ends of each range, to `precision`.
+    fn fuzzy_truncate(self, precision: Precision) -> Expr;
+
+    /// Whether each date or range contains the date or range in `other`,
+    /// which may also be a single value.
+    fn fuzzy_contains(self, other: Self) -> Expr;
+
+    /// Whether each date or range overlaps the date or range in `other`,
+    /// which may also be a single value.
+    fn fuzzy_overlaps(self, other: Self) -> Expr;
+}
+
+impl FuzzyDateExpr for Expr {
+    fn fuzzy_parse(self, strict: bool) -> Expr {
+        self.map(
+            move |column| {
+                let dates = parse_strings(&column, strict, str::parse::<FuzzyDate>)?;
+                write_dates(column.name().clone(), &dates).map(IntoColumn::into_column)
+            },
+            with_dtype(date_dtype),
+        )
+    }
+
+    fn fuzzy_parse_range(self, strict: bool) -> Expr {
+        self.map(
+            move |column| {
+                let ranges = parse_strings(&column, strict, str::parse::<FuzzyDateRange>)?;
+                write_ranges(column.name().clone(), &ranges).map(IntoColumn::into_column)
+            },
+            with_dtype(range_dtype),
+        )
+    }
+
+    fn fuzzy_lower_bound(self) -> Expr {
+        self.map(
+            |column| bound_column(&column, |(start, _)| start.lower_bound()),
+            with_dtype(|| DataType::Date),
+        )
+    }
+
+    fn fuzzy_upper_bound(self) -> Expr {
+        self.map(
+            |column| bound_column(&column, |(_, end)| end.upper_bound_inclusive()),
+            with_dtype(|| DataType::Date),
+        )
+    }
+
+    fn fuzzy_precision(self) -> Expr {
+        self.map(
+            |column| {
+                let dates = read_dates(column.as_materialized_series())?;
+                let precisions = dates
+                    .iter()
+                    .map(|date| date.map(|date| date.precision().to_string()));
+                Ok(
+                    StringChunked::from_iter_options(column.name().clone(), precisions)
+                        .into_column(),
+                )
+            },
+            with_dtype(|| DataType::String),
+        )
+    }
+
+    fn fuzzy_truncate(self, precision: Precision) -> Expr {
+        self.map(
+            move |column| {
+                let values = match Values::read(column.as_materialized_series())? {
+                    Values::Dates(dates) => Values::Dates(
+                        dates
+                            .into_iter()
+                            .map(|date| date.map(|date| date.truncate(precision)))
+                            .collect(),
+                    ),
+                    Values::Ranges(ranges) => Values::Ranges(
+                        ranges
+                            .into_iter()
+                            .map(|range| {
+                                range.and_then(|range| {
+                                    FuzzyDateRange::new(
+                                        range.start().truncate(precision),
+                                        range.end().truncate(precision),
+                                    )
+                                    .ok()
+                                })
+                            })
+                            .collect(),
+                    ),
+                };
+                write_values(column.name().clone(), &values).map(IntoColumn::into_column)
+            },
+            same_dtype(),
+        )
+    }
+
+    fn fuzzy_contains(self, other: Self) -> Expr {
+        self.map_many(
+            |columns| {
+                compare_columns(columns, |outer, inner| {
+                    outer.0.lower_bound() <= inner.0.lower_bound()
+                        && inner.1.upper_bound_inclusive() <= outer.1.upper_bound_inclusive()
+                })
+            },
+            &[other],
+            boolean_of_first(),
+        )
+    }
+
+    fn fuzzy_overlaps(self, other: Self) -> Expr {
+        self.map_many(
+            |columns| {
+                compare_columns(columns, |a, b| {
+                    a.0.lower_bound() <= b.1.upper_bound_inclusive()
+                        && b.0.lower_bound() <= a.1.upper_bound_inclusive()
+                })
+            },
+            &[other],
+            boolean_of_first(),
+        )
+    }
+}
+
+fn bound_column(
+    column: &Column,
+    bound: impl Fn((FuzzyDate, FuzzyDate)) -> (u16, u8, u8),
+) -> PolarsResult<Column> {
+    let values = Values::read(column.as_materialized_series())?;
+    let days = (0..values.len()).map(|row| {
+        values
+            .bounds(row)
+            .map(|dates| days_since_epoch(bound(dates)))
+    });
+    Ok(Int32Chunked::from_iter_options(column.name().clone(), days)
+        .into_series()
+        .into_date()
+        .into_column())
+}
+
+/// Compares the `(earliest, latest)` dates of each row of the first two
+/// columns, broadcasting single values.
+fn compare_columns(
+    columns: &mut [Column],
+    compare: impl Fn((FuzzyDate, FuzzyDate), (FuzzyDate, FuzzyDate)) -> bool,
+) -> PolarsResult<Column> {
+    let [left, right] = columns else {
+        return Err(compute_error(format!(
+            "expected two columns, found {}",
+            columns.len()
+        )));
+    };
+    let lhs = Values::read(left.as_materialized_series())?;
+    let rhs = Values::read(right.as_materialized_series())?;
+    if lhs.len() != rhs.len() && lhs.len() != 1 && rhs.len() != 1 {
+        return Err(compute_error(format!(
+            "cannot compare columns of lengths {} and {}",
+            lhs.len(),
+            rhs.len()
+        )));
+    }
+    let len = lhs.len().max(rhs.len());
+    let results = (0..len).map(|row| {
+        lhs.bounds(row)
+            .zip(rhs.bounds(row))
+            .map(|(a, b)| compare(a, b))
+    });
+    Ok(BooleanChunked::from_iter_options(left.name().clone(), results).into_column())
+}
+
+#[cfg(test)]
+mod tests {
+    use ::polars::prelude::{DataFrame, IntoLazy, NamedFrom, col, lit};
+
+    use super::*;
+    use crate::test_utils::{fuzzy_day, fuzzy_month, fuzzy_year};
+
+    fn frame() -> DataFrame {
+        DataFrame::new_infer_height(vec![
+            Column::new(
+                "date".into(),
+                [Some("1991"), Some("2024-02"), None, Some("2024-02-29")],
+            ),
+            Column::new(
+                "span".into(),
+                [
+                    Some("1990/1992"),
+                    Some("2024-03/2025"),
+                    Some("2020/2021"),
+                    None,
+                ],
+            ),
+        ])
+        .expect("expected frame")
+    }
+
+    fn parsed() -> DataFrame {
+        frame()
+            .lazy()
+            .with_columns([
+                col("date").fuzzy_parse(true),
+                col("span").fuzzy_parse_range(true),
+            ])
+            .collect()
+            .expect("expected parsed frame")
+    }
+
+    #[test]
+    fn test_days_since_epoch() {
+        assert_eq!(days_since_epoch((1970, 1, 1)), 0);
+        assert_eq!(days_since_epoch((2000, 3, 1)), 11_017);
+        assert_eq!(days_since_epoch((1969, 12, 31)), -1);
+        assert_eq!(days_since_epoch((1, 1, 1)), -719_162);
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        let frame = parsed();
+        assert_eq!(
+            frame.column("date").map(Column::dtype).ok(),
+            Some(&date_dtype())
+        );
+        let dates = read_dates(
+            frame
+                .column("date")
+                .expect("expected column")
+                .as_materialized_series(),
+        )
+        .expect("expected dates");
+        assert_eq!(
+            dates,
+            [
+                Some(fuzzy_year(1991)),
+                Some(fuzzy_month(2024, 2)),
+                None,
+                Some(fuzzy_day(2024, 2, 29)),
+            ]
+        );
+        let ranges = read_ranges(
+            frame
+                .column("span")
+                .expect("expected column")
+                .as_materialized_series(),
+        )
+        .expect("expected ranges");
+        assert_eq!(ranges[3], None);
+        assert_eq!(ranges[1].map(|range| range.end()), Some(fuzzy_year(2025)));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let frame =
+            DataFrame::new_infer_height(vec![Column::new("date".into(), ["2024-02-30", "2024"])])
+                .expect("expected frame");
+        assert!(
+            frame
+                .clone()
+                .lazy()
+                .select([col("date").fuzzy_parse(true)])
+                .collect()
+                .is_err()
+        );
+        let lenient = frame
+            .lazy()
+            .select([col("date").fuzzy_parse(false).fuzzy_precision()])
+            .collect()
+            .expect("expected lenient parse");
+        let precision: Vec<_> = lenient
+            .column("date")
+            .and_then(Column::str)
+            .expect("expected strings")
+            .iter()
+            .collect();
+        assert_eq!(precision, [None, Some("year")]);
+    }
+
+    #[test]
+    fn test_bounds_and_precision() {
+        let frame = parsed()
+            .lazy()
+            .select([
+                col("date").fuzzy_lower_bound().alias("lower"),
+                col("date").fuzzy_upper_bound().alias("upper"),
+                col("span").fuzzy_upper_bound().alias("span_upper"),
+                col("date").fuzzy_precision().alias("precision"),
+            ])
+            .collect()
+            .expect("expected bounds");
+
+        let days = |name: &str| -> Vec<Option<i32>> {
+            frame
+                .column(name)
+                .expect("expected column")
+                .as_materialized_series()
+                .to_physical_repr()
+                .i32()
+                .expect("expected days")
+                .iter()
+                .collect()
+        };
+        assert_eq!(
+            frame.column("lower").map(Column::dtype).ok(),
+            Some(&DataType::Date)
+        );
+        assert_eq!(
+            days("lower"),
+            [
+                Some(days_since_epoch((1991, 1, 1))),
+                Some(days_since_epoch((2024, 2, 1))),
+                None,
+                Some(days_since_epoch((2024, 2, 29))),
+            ]
+        );
+        assert_eq!(days("upper")[1], Some(days_since_epoch((2024, 2, 29))));
+        assert_eq!(
+            days("span_upper")[0],
+            Some(days_since_epoch((1992, 12, 31)))
+        );
+
+        let precision: Vec<_> = frame
+            .column("precision")
+            .and_then(Column::str)
+            .expect("expected strings")
+            .iter()
+            .collect();
+        assert_eq!(precision, [Some("year"), Some("month"), None, Some("day")]);
+    }
+
+    #[test]
+    fn test_truncate() {
+        let frame = parsed()
+            .lazy()
+            .select([
+                col("date").fuzzy_truncate(Precision::Month),
+                col("span").fuzzy_truncate(Precision::Year),
+            ])
+            .collect()
+            .expect("expected truncation");
+        let dates = read_dates(
+            frame
+                .column("date")
+                .expect("expected column")
+                .as_materialized_series(),
+        )
+        .expect("expected dates");
+        assert_eq!(dates[3], Some(fuzzy_month(2024, 2)));
+        let ranges = read_ranges(
+            frame
+                .column("span")
+                .expect("expected column")
+                .as_materialized_series(),
+        )
+        .expect("expected ranges");
+        assert_eq!(ranges[1].map(|range| range.start()), Some(fuzzy_year(2024)));
+    }
+
+    #[test]
+    fn test_contains_and_overlaps() {
+        let frame = parsed()
+            .lazy()
+            .select([
+                col("span")
+                    .fuzzy_contains(col("date"))
+                    .alias("span_contains"),
+                col("date")
+                    .fuzzy_contains(lit("2024-02-10").fuzzy_parse(true))
+                    .alias("date_contains"),
+                col("span")
+                    .fuzzy_overlaps(lit("2024/2024").fuzzy_parse_range(true))
+                    .alias("overlaps"),
+            ])
+            .collect()
+            .expect("expected comparisons");
+
+        let flags = |name: &str| -> Vec<Option<bool>> {
+            frame
+                .column(name)
+                .and_then(Column::bool)
+                .expect("expected booleans")
+                .iter()
+                .collect()
+        };
+        assert_eq!(
+            flags("span_contains"),
+            [Some(true), Some(false), None, None]
+        );
+        assert_eq!(
+            flags("date_contains"),
+            [Some(false), Some(true), None, Some(false)]
+        );
+        assert_eq!(
+            flags("overlaps"),
+            [Some(false), Some(true), Some(false), None]
+        );
+
+        let series = Series::new("n".into(), [1_u32]);
+        assert!(Values::read(&series).is_err());
+
+        // Lengths must match unless one side is a single value
+        let dates = parsed()
+            .column("date")
+            .expect("expected date column")
+            .clone();
+        let mut columns = [dates.slice(0, 3), dates.slice(0, 2)];
+        assert!(compare_columns(&mut columns, |_, _| true).is_err());
+        let mut columns = [dates.slice(0, 3), dates.slice(0, 1)];
+        let flags = compare_columns(&mut columns, |_, _| true).expect("expected broadcast");
+        assert_eq!(flags.len(), 3);
+    }
+}
@@ -1,6 +1,16 @@
 //! Prelude module for `fuzzy_date` crate.
 //!
-//! Re-exports commonly used derive macros from `derive_more`.
+//! Re-exports commonly used derive macros from `derive_more`, and the `alloc`
+//! items that the standard prelude would otherwise provide.
 
+#[cfg(feature = "alloc")]
+#[allow(unused_imports)]
+pub use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 #[allow(unused_imports)]
 pub use derive_more::{Deref, Display, From, FromStr, Into, TryInto};
@@ -0,0 +1,129 @@
+//! `proptest` integration.
+//!
+//! `any::<FuzzyDate>()` draws from the default [`DateDistribution`];
+//! `any_with::<FuzzyDate>(dist)` and `any_with::<FuzzyDateRange>(dist)` draw
+//! from a custom one. Dates shrink towards year precision, the first year of
+//! the span, January and the first of the month.
+
+use ::proptest::{
+    arbitrary::{Arbitrary, any_with},
+    strategy::{BoxedStrategy, Strategy},
+};
+
+use crate::{
+    Day, FuzzyDate, FuzzyDateRange, MAX_MONTH, MAX_YEAR, MIN_DAY, Month, Year,
+    distribution::{self, DateDistribution},
+};
+
+impl Arbitrary for Year {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        (1..=MAX_YEAR)
+            .prop_filter_map("valid year", |year| Self::new(year).ok())
+            .boxed()
+    }
+}
+
+impl Arbitrary for Month {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        (1..=MAX_MONTH)
+            .prop_filter_map("valid month", |month| Self::new(month).ok())
+            .boxed()
+    }
+}
+
+/// Any day that is valid in some month, `1..=31`.
+impl Arbitrary for Day {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        (MIN_DAY..=31)
+            .prop_filter_map("valid day", |day| Self::try_from(day).ok())
+            .boxed()
+    }
+}
+
+impl Arbitrary for FuzzyDate {
+    type Parameters = DateDistribution;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(dist: DateDistribution) -> Self::Strategy {
+        (
+            dist.years(),
+            0..distribution::weight_total(&dist),
+            1..=MAX_MONTH,
+            MIN_DAY..=31,
+        )
+            .prop_map(move |(year, pick, month, day)| {
+                distribution::date(&dist, year, pick, month, day)
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for FuzzyDateRange {
+    type Parameters = DateDistribution;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(dist: DateDistribution) -> Self::Strategy {
+        (any_with::<FuzzyDate>(dist), any_with::<FuzzyDate>(dist))
+            .prop_map(|(a, b)| distribution::range(a, b))
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::proptest::{prelude::*, test_runner::TestRunner};
+
+    use super::*;
+    use crate::{Precision, test_utils::year};
+
+    proptest! {
+        #[test]
+        fn test_dates_round_trip(date in any::<FuzzyDate>()) {
+            prop_assert_eq!(date.to_string().parse::<FuzzyDate>(), Ok(date));
+        }
+
+        #[test]
+        fn test_ranges_are_ordered(range in any::<FuzzyDateRange>()) {
+            prop_assert!(range.start() <= range.end());
+        }
+
+        #[test]
+        fn test_components(year in any::<Year>(), month in any::<Month>(), day in any::<Day>()) {
+            prop_assert!((1..=MAX_YEAR).contains(&year.get()));
+            prop_assert!((1..=MAX_MONTH).contains(&month.get()));
+            prop_assert!((1..=31).contains(&day.get()));
+        }
+
+        #[test]
+        fn test_distribution(date in any_with::<FuzzyDate>(
+            DateDistribution::new()
+                .with_years(year(2000), year(2004))
+                .with_precision_weights(0, 0, 1)
+        )) {
+            prop_assert!((2000..=2004).contains(&date.year().get()));
+            prop_assert_eq!(date.precision(), Precision::Day);
+        }
+    }
+
+    #[test]
+    fn test_shrinks_to_simplest_date() {
+        let mut runner = TestRunner::deterministic();
+        let result = runner.run(&any::<FuzzyDate>(), |date| {
+            prop_assert!(date.year().get() < 1500);
+            Ok(())
+        });
+        let Err(::proptest::test_runner::TestError::Fail(_, minimal)) = result else {
+            panic!("expected a failing case, got {result:?}");
+        };
+        assert_eq!(minimal, crate::test_utils::fuzzy_year(1500));
+    }
+}
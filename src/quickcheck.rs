@@ -0,0 +1,196 @@
+//! `quickcheck` integration.
+//!
+//! `Arbitrary` draws from the default [`DateDistribution`];
+//! [`DateDistribution::quickcheck_date`] and
+//! [`DateDistribution::quickcheck_range`] draw from a custom one. Shrinking
+//! tries coarser precision first, then smaller years, months and days, and
+//! does not keep values inside a custom distribution.
+
+use ::quickcheck::{Arbitrary, Gen};
+
+use crate::{
+    Day, FuzzyDate, FuzzyDateRange, MAX_MONTH, MAX_YEAR, MIN_DAY, Month, Precision, Year,
+    days_in_month,
+    distribution::{self, DateDistribution},
+};
+
+/// A value in `first..=last` drawn from `g`.
+fn within<T: Copy + Into<u64> + TryFrom<u64>>(g: &mut Gen, first: T, last: T) -> T {
+    let (first_value, last_value) = (first.into(), last.into());
+    let span = last_value.saturating_sub(first_value).saturating_add(1);
+    T::try_from(first_value + u64::arbitrary(g) % span).unwrap_or(first)
+}
+
+impl DateDistribution {
+    /// Draws a date from this distribution.
+    pub fn quickcheck_date(&self, g: &mut Gen) -> FuzzyDate {
+        let years = self.years();
+        distribution::date(
+            self,
+            within(g, *years.start(), *years.end()),
+            within(g, 0, distribution::weight_total(self) - 1),
+            within(g, 1, MAX_MONTH),
+            within(g, MIN_DAY, 31),
+        )
+    }
+
+    /// Draws a range whose ends both come from this distribution.
+    pub fn quickcheck_range(&self, g: &mut Gen) -> FuzzyDateRange {
+        distribution::range(self.quickcheck_date(g), self.quickcheck_date(g))
+    }
+}
+
+impl Arbitrary for Year {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Self::new(within(g, 1, MAX_YEAR))
+            .unwrap_or_else(|_| unreachable!("drawn years are always in range"))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.get().shrink().filter_map(|year| Self::new(year).ok()))
+    }
+}
+
+impl Arbitrary for Month {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Self::new(within(g, 1, MAX_MONTH))
+            .unwrap_or_else(|_| unreachable!("drawn months are always in range"))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            self.get()
+                .shrink()
+                .filter_map(|month| Self::new(month).ok()),
+        )
+    }
+}
+
+/// Any day that is valid in some month, `1..=31`.
+impl Arbitrary for Day {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Self::try_from(within(g, MIN_DAY, 31))
+            .unwrap_or_else(|_| unreachable!("drawn days are always in range"))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            self.get()
+                .shrink()
+                .filter_map(|day| Self::try_from(day).ok()),
+        )
+    }
+}
+
+impl Arbitrary for FuzzyDate {
+    fn arbitrary(g: &mut Gen) -> Self {
+        DateDistribution::new().quickcheck_date(g)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let date = *self;
+        let (year, month, day) = date.to_columns();
+        let coarser = [Precision::Year, Precision::Month]
+            .into_iter()
+            .filter(move |&precision| precision < date.precision())
+            .map(move |precision| date.truncate(precision));
+        let years = year
+            .shrink()
+            .filter_map(move |year| with_columns(year, month, day));
+        let months = month
+            .into_iter()
+            .flat_map(|month| month.shrink())
+            .filter_map(move |month| with_columns(year, Some(month), day));
+        let days = day
+            .into_iter()
+            .flat_map(|day| day.shrink())
+            .filter_map(move |day| with_columns(year, month, Some(day)));
+        Box::new(coarser.chain(years).chain(months).chain(days))
+    }
+}
+
+/// Rebuilds a shrunk date, pulling the day back into a shorter month.
+fn with_columns(year: u16, month: Option<u8>, day: Option<u8>) -> Option<FuzzyDate> {
+    if year == 0 || year > MAX_YEAR || month == Some(0) || day == Some(0) {
+        return None;
+    }
+    let day = day
+        .zip(month)
+        .map(|(day, month)| day.min(days_in_month(year, month)));
+    FuzzyDate::from_columns(year, month, day).ok()
+}
+
+impl Arbitrary for FuzzyDateRange {
+    fn arbitrary(g: &mut Gen) -> Self {
+        DateDistribution::new().quickcheck_range(g)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let (start, end) = self.dates();
+        let starts = start
+            .shrink()
+            .filter_map(move |start| Self::new(start, end).ok());
+        let ends = end
+            .shrink()
+            .filter_map(move |end| Self::new(start, end).ok());
+        Box::new(starts.chain(ends))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::quickcheck::{QuickCheck, TestResult};
+
+    use super::*;
+    use crate::test_utils::{fuzzy_day, fuzzy_month, fuzzy_year, year};
+
+    #[test]
+    fn test_dates_round_trip() {
+        fn prop(date: FuzzyDate) -> bool {
+            date.to_string().parse::<FuzzyDate>() == Ok(date)
+        }
+        QuickCheck::new().quickcheck(prop as fn(FuzzyDate) -> bool);
+    }
+
+    #[test]
+    fn test_ranges_are_ordered() {
+        fn prop(range: FuzzyDateRange) -> bool {
+            range.start() <= range.end()
+        }
+        QuickCheck::new().quickcheck(prop as fn(FuzzyDateRange) -> bool);
+    }
+
+    #[test]
+    fn test_components() {
+        fn prop(year: Year, month: Month, day: Day) -> TestResult {
+            TestResult::from_bool(
+                (1..=MAX_YEAR).contains(&year.get())
+                    && (1..=MAX_MONTH).contains(&month.get())
+                    && (1..=31).contains(&day.get()),
+            )
+        }
+        QuickCheck::new().quickcheck(prop as fn(Year, Month, Day) -> TestResult);
+    }
+
+    #[test]
+    fn test_distribution() {
+        let dist = DateDistribution::new()
+            .with_years(year(2000), year(2004))
+            .with_precision_weights(0, 0, 1);
+        let mut g = Gen::new(100);
+        for _ in 0..1000 {
+            let date = dist.quickcheck_date(&mut g);
+            assert!((2000..=2004).contains(&date.year().get()), "{date}");
+            assert_eq!(date.precision(), Precision::Day);
+        }
+    }
+
+    #[test]
+    fn test_shrink() {
+        let shrunk: Vec<_> = fuzzy_day(2024, 3, 31).shrink().collect();
+        assert_eq!(shrunk[..2], [fuzzy_year(2024), fuzzy_month(2024, 3)]);
+        assert!(shrunk.contains(&fuzzy_day(2024, 2, 29)));
+        assert!(shrunk.iter().all(|date| *date != fuzzy_day(2024, 3, 31)));
+        assert_eq!(fuzzy_year(1).shrink().count(), 0);
+    }
+}
@@ -1,20 +1,30 @@
-use std::{cmp::Ordering, str::FromStr};
+use core::{cmp::Ordering, str::FromStr};
 
-use serde::{Deserialize, Serialize};
-
-use crate::{FuzzyDate, ParseError, RANGE_SEPARATOR, prelude::*};
+#[cfg(feature = "alloc")]
+use crate::RANGE_SEPARATOR;
+#[cfg(not(feature = "alloc"))]
+use crate::{ByteParseError, ByteRangeError};
+use crate::{FuzzyDate, ParseError, prelude::*};
 
 /// Represents a range between two fuzzy dates (inclusive).
 /// The start date must be less than or equal to the end date.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
 #[display(fmt = "{start}/{end}")]
+#[cfg_attr(
+    feature = "diesel",
+    derive(::diesel::expression::AsExpression),
+    diesel(sql_type = ::diesel::sql_types::Text)
+)]
 pub struct FuzzyDateRange {
     start: FuzzyDate,
     end: FuzzyDate,
 }
 
 /// Error type for date range operations.
+///
+/// Non-exhaustive, as the `alloc` feature adds `InvalidFormat`.
 #[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
 pub enum RangeError {
     /// Start date is after end date.
     #[error("Invalid date range: start ({start}) is after end ({end})")]
@@ -25,8 +35,14 @@ pub enum RangeError {
     ParseError(#[from] ParseError),
 
     /// Invalid range format.
+    #[cfg(feature = "alloc")]
     #[error("Invalid range format: {0}")]
     InvalidFormat(String),
+
+    /// Missing or extra range separator at the given byte offset. Without the
+    /// `alloc` feature, `FromStr` reports every format error this way.
+    #[error("Invalid range format at byte {0}")]
+    InvalidFormatAt(usize),
 }
 
 impl FuzzyDateRange {
@@ -35,11 +51,11 @@ impl FuzzyDateRange {
     ///
     /// # Errors
     /// Returns `RangeError::InvalidRange` if start > end.
-    pub fn new(start: FuzzyDate, end: FuzzyDate) -> Result<Self, RangeError> {
-        if start > end {
-            return Err(RangeError::InvalidRange { start, end });
+    pub const fn new(start: FuzzyDate, end: FuzzyDate) -> Result<Self, RangeError> {
+        match ordered(start, end) {
+            Some(range) => Ok(range),
+            None => Err(RangeError::InvalidRange { start, end }),
         }
-        Ok(Self { start, end })
     }
 
     /// Returns the start date of the range
@@ -134,8 +150,46 @@ impl FuzzyDateRange {
         let end = FuzzyDate::from_columns(end_year, end_month, end_day)?;
         Self::new(start, end)
     }
+
+    /// Converts to `i32` database columns, for drivers without unsigned
+    /// integer types: (`start_year`, `start_month`, `start_day`, `end_year`, `end_month`, `end_day`)
+    pub fn to_integer_columns(
+        &self,
+    ) -> (i32, Option<i32>, Option<i32>, i32, Option<i32>, Option<i32>) {
+        let (sy, sm, sd) = self.start.to_integer_columns();
+        let (ey, em, ed) = self.end.to_integer_columns();
+        (sy, sm, sd, ey, em, ed)
+    }
+
+    /// Creates from `i32` database columns: (`start_year`, `start_month`, `start_day`, `end_year`, `end_month`, `end_day`)
+    ///
+    /// # Errors
+    /// Returns `RangeError` if the dates are invalid or start > end.
+    #[cfg(feature = "alloc")]
+    pub fn from_integer_columns(
+        start_year: i32,
+        start_month: Option<i32>,
+        start_day: Option<i32>,
+        end_year: i32,
+        end_month: Option<i32>,
+        end_day: Option<i32>,
+    ) -> Result<Self, RangeError> {
+        let start = FuzzyDate::from_integer_columns(start_year, start_month, start_day)?;
+        let end = FuzzyDate::from_integer_columns(end_year, end_month, end_day)?;
+        Self::new(start, end)
+    }
 }
 
+/// The range from `start` to `end`, or `None` if `start > end`. Compares keys,
+/// whose order matches `Ord`, because `Ord::cmp` cannot be called in a `const fn`.
+pub const fn ordered(start: FuzzyDate, end: FuzzyDate) -> Option<FuzzyDateRange> {
+    if u32::from_be_bytes(start.to_key()) > u32::from_be_bytes(end.to_key()) {
+        return None;
+    }
+    Some(FuzzyDateRange { start, end })
+}
+
+#[cfg(feature = "alloc")]
 impl FromStr for FuzzyDateRange {
     type Err = RangeError;
 
@@ -171,6 +225,25 @@ impl FromStr for FuzzyDateRange {
     }
 }
 
+/// Without `alloc`, parsing goes through the allocation-free byte parser.
+/// Offsets in `InvalidFormatAt` are relative to `s`.
+#[cfg(not(feature = "alloc"))]
+impl FromStr for FuzzyDateRange {
+    type Err = RangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let offset = s.len() - s.trim_start().len();
+        Self::parse_bytes(s.trim().as_bytes()).map_err(|err| match err {
+            ByteRangeError::InvalidRange { start, end } => RangeError::InvalidRange { start, end },
+            ByteRangeError::ParseError(ByteParseError::InvalidFormat(at)) => {
+                ParseError::InvalidFormatAt(offset + at).into()
+            }
+            ByteRangeError::ParseError(err) => RangeError::ParseError(err.into()),
+            ByteRangeError::InvalidFormat(at) => RangeError::InvalidFormatAt(offset + at),
+        })
+    }
+}
+
 impl PartialOrd for FuzzyDateRange {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -187,25 +260,6 @@ impl Ord for FuzzyDateRange {
     }
 }
 
-impl Serialize for FuzzyDateRange {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        serializer.serialize_str(&self.to_string())
-    }
-}
-
-impl<'de> Deserialize<'de> for FuzzyDateRange {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        let s = String::deserialize(deserializer)?;
-        s.parse().map_err(serde::de::Error::custom)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -455,6 +509,47 @@ mod tests {
         assert_eq!(range, restored);
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_integer_columns() {
+        let range = FuzzyDateRange::new(fuzzy_year(1990), fuzzy_day(2000, 12, 31))
+            .expect("failed to construct range for column conversion test");
+        let (sy, sm, sd, ey, em, ed) = range.to_integer_columns();
+        assert_eq!(
+            (sy, sm, sd, ey, em, ed),
+            (1990, None, None, 2000, Some(12), Some(31))
+        );
+        assert_eq!(
+            FuzzyDateRange::from_integer_columns(sy, sm, sd, ey, em, ed),
+            Ok(range)
+        );
+
+        assert!(matches!(
+            FuzzyDateRange::from_integer_columns(2000, None, None, 1990, None, None),
+            Err(RangeError::InvalidRange { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(not(feature = "alloc"))]
+    fn test_from_str_offsets() {
+        assert_eq!(
+            " 1990/2000/2010".parse::<FuzzyDateRange>(),
+            Err(RangeError::InvalidFormatAt(10))
+        );
+        assert_eq!(
+            " 1990-XX/2000".parse::<FuzzyDateRange>(),
+            Err(RangeError::ParseError(ParseError::InvalidFormatAt(6)))
+        );
+        assert_eq!(
+            " 2000/1990".parse::<FuzzyDateRange>(),
+            Err(RangeError::InvalidRange {
+                start: fuzzy_year(2000),
+                end: fuzzy_year(1990),
+            })
+        );
+    }
+
     #[test]
     fn test_mixed_precision_range() {
         let start = fuzzy_year(1990);
@@ -562,6 +657,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn test_too_many_range_separators() {
         // Too many '/' separators
         let result = "2000/2001/2002".parse::<FuzzyDateRange>();
@@ -572,6 +668,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn test_no_range_separator() {
         // Missing '/' separator
         let result = "20002001".parse::<FuzzyDateRange>();
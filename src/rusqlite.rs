@@ -0,0 +1,297 @@
+//! `rusqlite` integration.
+//!
+//! `FuzzyDate` and `FuzzyDateRange` are stored as ISO text through
+//! `ToSql`/`FromSql`, or across the three- and six-column layouts of
+//! `to_columns` with the `bind_columns`/`from_row` helpers.
+
+use ::rusqlite::{
+    Error, Result, Row, Statement,
+    types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, Type, ValueRef},
+};
+
+use crate::{FuzzyDate, FuzzyDateRange};
+
+impl ToSql for FuzzyDate {
+    fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
+        Ok(self.to_string().into())
+    }
+}
+
+impl FromSql for FuzzyDate {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        value
+            .as_str()?
+            .parse()
+            .map_err(|err| FromSqlError::Other(Box::new(err)))
+    }
+}
+
+impl ToSql for FuzzyDateRange {
+    fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
+        Ok(self.to_string().into())
+    }
+}
+
+impl FromSql for FuzzyDateRange {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        value
+            .as_str()?
+            .parse()
+            .map_err(|err| FromSqlError::Other(Box::new(err)))
+    }
+}
+
+impl FuzzyDate {
+    /// Binds the year, month and day columns to the statement parameters
+    /// starting at the one-based index `first`.
+    ///
+    /// # Errors
+    /// Returns the `rusqlite::Error` from binding a parameter.
+    pub fn bind_columns(&self, statement: &mut Statement<'_>, first: usize) -> Result<()> {
+        let (year, month, day) = self.to_columns();
+        statement.raw_bind_parameter(first, year)?;
+        statement.raw_bind_parameter(first + 1, month)?;
+        statement.raw_bind_parameter(first + 2, day)
+    }
+
+    /// Reads the year, month and day columns starting at the zero-based
+    /// column `first`.
+    ///
+    /// # Errors
+    /// Returns the `rusqlite::Error` from reading a column, or
+    /// `Error::FromSqlConversionFailure` if the columns are not a valid date.
+    pub fn from_row(row: &Row<'_>, first: usize) -> Result<Self> {
+        Self::from_columns(row.get(first)?, row.get(first + 1)?, row.get(first + 2)?)
+            .map_err(|err| Error::FromSqlConversionFailure(first, Type::Integer, Box::new(err)))
+    }
+}
+
+impl FuzzyDateRange {
+    /// Binds the six start and end columns to the statement parameters
+    /// starting at the one-based index `first`.
+    ///
+    /// # Errors
+    /// Returns the `rusqlite::Error` from binding a parameter.
+    pub fn bind_columns(&self, statement: &mut Statement<'_>, first: usize) -> Result<()> {
+        self.start().bind_columns(statement, first)?;
+        self.end().bind_columns(statement, first + 3)
+    }
+
+    /// Reads the six start and end columns starting at the zero-based column
+    /// `first`.
+    ///
+    /// # Errors
+    /// Returns the `rusqlite::Error` from reading a column, or
+    /// `Error::FromSqlConversionFailure` if the columns are not a valid range.
+    pub fn from_row(row: &Row<'_>, first: usize) -> Result<Self> {
+        Self::new(
+            FuzzyDate::from_row(row, first)?,
+            FuzzyDate::from_row(row, first + 3)?,
+        )
+        .map_err(|err| Error::FromSqlConversionFailure(first, Type::Integer, Box::new(err)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::rusqlite::{Connection, params};
+
+    use super::*;
+    use crate::{
+        SqlColumns, SqlRangeColumns,
+        test_utils::{fuzzy_day, fuzzy_month, fuzzy_year},
+    };
+
+    fn connection() -> Connection {
+        Connection::open_in_memory().expect("expected in-memory database")
+    }
+
+    #[test]
+    fn test_text_round_trip() {
+        let conn = connection();
+        conn.execute_batch("CREATE TABLE t (date TEXT, span TEXT)")
+            .expect("expected table");
+        let range = FuzzyDateRange::new(fuzzy_month(2020, 3), fuzzy_day(2026, 2, 13))
+            .expect("expected valid test range");
+        conn.execute(
+            "INSERT INTO t VALUES (?1, ?2)",
+            params![fuzzy_month(2024, 2), range],
+        )
+        .expect("expected insert");
+
+        let stored: String = conn
+            .query_row("SELECT date FROM t", [], |row| row.get(0))
+            .expect("expected row");
+        assert_eq!(stored, "2024-02");
+
+        let (date, span): (FuzzyDate, FuzzyDateRange) = conn
+            .query_row("SELECT date, span FROM t", [], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .expect("expected row");
+        assert_eq!(date, fuzzy_month(2024, 2));
+        assert_eq!(span, range);
+    }
+
+    #[test]
+    fn test_invalid_text() {
+        let err = connection()
+            .query_row("SELECT '2024-13'", [], |row| row.get::<_, FuzzyDate>(0))
+            .expect_err("expected invalid month to fail");
+        assert!(matches!(
+            err,
+            Error::FromSqlConversionFailure(0, Type::Text, _)
+        ));
+    }
+
+    #[test]
+    fn test_column_round_trip() {
+        let conn = connection();
+        conn.execute_batch(
+            "CREATE TABLE t (id INTEGER, born_year INTEGER NOT NULL, born_month INTEGER, born_day INTEGER,
+                             active_start_year INTEGER NOT NULL, active_start_month INTEGER, active_start_day INTEGER,
+                             active_end_year INTEGER NOT NULL, active_end_month INTEGER, active_end_day INTEGER)",
+        )
+        .expect("expected table");
+
+        let born = fuzzy_month(1991, 8);
+        let active = FuzzyDateRange::new(fuzzy_year(2010), fuzzy_day(2026, 2, 13))
+            .expect("expected valid test range");
+        let mut insert = conn
+            .prepare("INSERT INTO t VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)")
+            .expect("expected statement");
+        insert.raw_bind_parameter(1, 7).expect("expected bind");
+        born.bind_columns(&mut insert, 2).expect("expected bind");
+        active.bind_columns(&mut insert, 5).expect("expected bind");
+        insert.raw_execute().expect("expected insert");
+
+        let stored: (u16, Option<u8>, Option<u8>) = conn
+            .query_row("SELECT born_year, born_month, born_day FROM t", [], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+            })
+            .expect("expected row");
+        assert_eq!(stored, (1991, Some(8), None));
+
+        let sql = format!(
+            "SELECT id, {}, {} FROM t",
+            SqlColumns::prefixed("born").select_list(),
+            SqlRangeColumns::prefixed("active").select_list()
+        );
+        let (date, range) = conn
+            .query_row(&sql, [], |row| {
+                Ok((
+                    FuzzyDate::from_row(row, 1)?,
+                    FuzzyDateRange::from_row(row, 4)?,
+                ))
+            })
+            .expect("expected row");
+        assert_eq!(date, born);
+        assert_eq!(range, active);
+    }
+
+    #[test]
+    fn test_invalid_columns() {
+        let err = connection()
+            .query_row("SELECT 2023, 2, 29", [], |row| FuzzyDate::from_row(row, 0))
+            .expect_err("expected invalid day to fail");
+        assert!(matches!(
+            err,
+            Error::FromSqlConversionFailure(0, Type::Integer, _)
+        ));
+
+        let err = connection()
+            .query_row("SELECT 2026, NULL, NULL, 2020, NULL, NULL", [], |row| {
+                FuzzyDateRange::from_row(row, 0)
+            })
+            .expect_err("expected reversed range to fail");
+        assert!(matches!(
+            err,
+            Error::FromSqlConversionFailure(0, Type::Integer, _)
+        ));
+    }
+
+    #[test]
+    fn test_bound_sql_matches_rust() {
+        let conn = connection();
+        conn.execute_batch("CREATE TABLE t (d_year INTEGER, d_month INTEGER, d_day INTEGER)")
+            .expect("expected table");
+        let mut dates = vec![fuzzy_year(1), fuzzy_year(9999), fuzzy_month(9999, 12)];
+        for year in [1900, 2000, 2023, 2024] {
+            dates.push(fuzzy_year(year));
+            for month in 1..=12 {
+                dates.push(fuzzy_month(year, month));
+            }
+            dates.push(fuzzy_day(year, 2, 28));
+        }
+        for date in &dates {
+            conn.execute("INSERT INTO t VALUES (?1, ?2, ?3)", date.to_columns())
+                .expect("expected insert");
+        }
+
+        let columns = SqlColumns::prefixed("d");
+        let sql = format!(
+            "SELECT {}, {}, {} FROM t",
+            columns.select_list(),
+            columns.lower_bound_sql(),
+            columns.upper_bound_sql()
+        );
+        let mut statement = conn.prepare(&sql).expect("expected statement");
+        let rows = statement
+            .query_map([], |row| {
+                Ok((
+                    FuzzyDate::from_row(row, 0)?,
+                    row.get::<_, String>(3)?,
+                    row.get::<_, String>(4)?,
+                ))
+            })
+            .expect("expected query");
+        let mut count = 0;
+        for row in rows {
+            let (date, lower, upper) = row.expect("expected row");
+            let (y, m, d) = date.lower_bound();
+            assert_eq!(lower, format!("{y:04}-{m:02}-{d:02}"), "date: {date}");
+            let (y, m, d) = date.upper_bound_inclusive();
+            assert_eq!(upper, format!("{y:04}-{m:02}-{d:02}"), "date: {date}");
+            count += 1;
+        }
+        assert_eq!(count, dates.len());
+    }
+
+    #[test]
+    fn test_range_filter_in_database() {
+        let conn = connection();
+        let columns = SqlRangeColumns::prefixed("r");
+        conn.execute_batch(&format!(
+            "CREATE TABLE t (name TEXT, {})",
+            columns.select_list()
+        ))
+        .expect("expected table");
+        for (name, range) in [
+            ("early", "2001/2003-06"),
+            ("mid", "2010-05/2012"),
+            ("late", "2020/2026"),
+        ] {
+            let range: FuzzyDateRange = range.parse().expect("expected valid test range");
+            let mut insert = conn
+                .prepare("INSERT INTO t VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)")
+                .expect("expected statement");
+            insert.raw_bind_parameter(1, name).expect("expected bind");
+            range.bind_columns(&mut insert, 2).expect("expected bind");
+            insert.raw_execute().expect("expected insert");
+        }
+
+        // Ranges overlapping 2003-06-15..=2011-01-01
+        let sql = format!(
+            "SELECT name FROM t WHERE {} >= ?1 AND {} <= ?2 ORDER BY name",
+            columns.upper_bound_sql(),
+            columns.lower_bound_sql()
+        );
+        let mut statement = conn.prepare(&sql).expect("expected statement");
+        let names: Vec<String> = statement
+            .query_map(["2003-06-15", "2011-01-01"], |row| row.get(0))
+            .expect("expected query")
+            .collect::<Result<_>>()
+            .expect("expected rows");
+        assert_eq!(names, ["early", "mid"]);
+    }
+}
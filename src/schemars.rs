@@ -0,0 +1,161 @@
+//! `schemars` integration.
+//!
+//! Both types are described as strings, matching their default serde form in
+//! JSON, with a `pattern` accepting everything `FromStr` accepts. The pattern
+//! checks shape only; component ranges such as month 1-12 or the day of the
+//! month are left to deserialization.
+
+use std::borrow::Cow;
+
+use ::schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+
+use crate::{FuzzyDate, FuzzyDateRange};
+
+/// `YYYY`, `YYYY-MM` or `YYYY-MM-DD`, with optional `+` signs and spaces
+/// around fields.
+macro_rules! iso {
+    () => {
+        r"\+?[0-9]+(\s*-\s*\+?[0-9]+){0,2}"
+    };
+}
+
+/// `MM/YYYY` or `MM/DD/YYYY`.
+macro_rules! month_first {
+    () => {
+        r"[0-9]{1,2}/([0-9]{1,2}/)?[0-9]+"
+    };
+}
+
+const DATE_PATTERN: &str = concat!(r"^\s*(", iso!(), "|", month_first!(), r")\s*$");
+
+/// Month-first dates cannot appear in a range: `/` is the range separator.
+const RANGE_PATTERN: &str = concat!(r"^\s*", iso!(), r"\s*/\s*", iso!(), r"\s*$");
+
+impl JsonSchema for FuzzyDate {
+    fn schema_name() -> Cow<'static, str> {
+        "FuzzyDate".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "fuzzy_date::FuzzyDate".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": DATE_PATTERN,
+            "description": "A date known to year, month or day precision. Written as \
+                ISO 8601 `YYYY`, `YYYY-MM` or `YYYY-MM-DD` with years 1-9999; \
+                month-first `MM/YYYY` and `MM/DD/YYYY` are also accepted. A value \
+                covers every day from its earliest to its latest possible date, \
+                and missing components are never filled in.",
+            "examples": ["2026", "2026-02", "2026-02-13"],
+        })
+    }
+}
+
+impl JsonSchema for FuzzyDateRange {
+    fn schema_name() -> Cow<'static, str> {
+        "FuzzyDateRange".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "fuzzy_date::FuzzyDateRange".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": RANGE_PATTERN,
+            "description": "An inclusive range between two ISO 8601 fuzzy dates \
+                separated by `/`, each at year, month or day precision. The start \
+                must not sort after the end.",
+            "examples": ["2020/2026", "2020-03/2026-02-13"],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::schemars::schema_for;
+    use regex::Regex;
+
+    use super::*;
+
+    fn pattern(schema: &Schema) -> Regex {
+        let pattern = schema
+            .get("pattern")
+            .and_then(|p| p.as_str())
+            .expect("expected pattern");
+        Regex::new(pattern).expect("expected valid pattern")
+    }
+
+    #[test]
+    fn test_date_pattern() {
+        let schema = schema_for!(FuzzyDate);
+        assert_eq!(schema.get("type").and_then(|t| t.as_str()), Some("string"));
+        let pattern = pattern(&schema);
+
+        for input in [
+            "2026",
+            "2026-02",
+            "2026-02-13",
+            "  2026 - 2 - 3 ",
+            "+2026-+02",
+            "0002026",
+            "02/2026",
+            "2/13/2026",
+            "12/31/99",
+        ] {
+            assert!(input.parse::<FuzzyDate>().is_ok(), "{input}");
+            assert!(pattern.is_match(input), "{input}");
+        }
+        for input in [
+            "",
+            "2026-",
+            "2026-02-13-01",
+            "2026/02",
+            "2026-02/13",
+            "2O26",
+            "123/2026",
+        ] {
+            assert!(input.parse::<FuzzyDate>().is_err(), "{input}");
+            assert!(!pattern.is_match(input), "{input}");
+        }
+
+        let examples = schema.get("examples").and_then(|e| e.as_array());
+        for example in examples.expect("expected examples") {
+            let example = example.as_str().expect("expected string example");
+            assert!(example.parse::<FuzzyDate>().is_ok(), "{example}");
+        }
+    }
+
+    #[test]
+    fn test_range_pattern() {
+        let schema = schema_for!(FuzzyDateRange);
+        let pattern = pattern(&schema);
+
+        for input in ["2020/2026", "2020-03/2026-02-13", " 2020 / 2026-02 "] {
+            assert!(input.parse::<FuzzyDateRange>().is_ok(), "{input}");
+            assert!(pattern.is_match(input), "{input}");
+        }
+        for input in ["2020", "2020/2026/2030", "03/2020/2026", "/2026"] {
+            assert!(input.parse::<FuzzyDateRange>().is_err(), "{input}");
+            assert!(!pattern.is_match(input), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_schema_reference() {
+        #[derive(JsonSchema)]
+        #[allow(dead_code)]
+        struct Person {
+            born: FuzzyDate,
+            active: Option<FuzzyDateRange>,
+        }
+
+        let schema = serde_json::to_value(schema_for!(Person)).expect("expected schema JSON");
+        assert_eq!(schema["properties"]["born"]["$ref"], "#/$defs/FuzzyDate");
+        assert_eq!(schema["$defs"]["FuzzyDateRange"]["type"], "string");
+    }
+}
@@ -0,0 +1,580 @@
+//! Serde support.
+//!
+//! By default `FuzzyDate` and `FuzzyDateRange` serialize as their ISO strings
+//! (`"2026-02"`, `"2020/2026-02-13"`) in human-readable formats such as JSON,
+//! TOML and YAML, and in the [`compact`] integer form in binary formats such as
+//! bincode and postcard. The adapter modules here select another
+//! representation per field with `#[serde(with = "...")]`, and each accepts
+//! `FuzzyDate`, `FuzzyDateRange` and an `Option` of either:
+//!
+//! | Module      | `FuzzyDate`                   | `FuzzyDateRange`                         |
+//! |-------------|-------------------------------|------------------------------------------|
+//! | [`columns`] | `[2026, 2, null]`             | `[[2020, null, null], [2026, 2, 13]]`    |
+//! | [`object`]  | `{"year": 2026, "month": 2}`  | `{"start": {"year": 2020}, "end": ...}`  |
+//! | [`compact`] | `PackedFuzzyDate` bits, `u32` | big-endian `to_key`, `u64`               |
+//!
+//! ```
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use fuzzy_date::FuzzyDate;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Person {
+//!     #[serde(with = "fuzzy_date::serde::object")]
+//!     born: FuzzyDate,
+//!     #[serde(with = "fuzzy_date::serde::columns")]
+//!     died: Option<FuzzyDate>,
+//! }
+//!
+//! let person = Person { born: "1991-08".parse()?, died: None };
+//! let json = serde_json::to_string(&person)?;
+//! assert_eq!(json, r#"{"born":{"year":1991,"month":8},"died":null}"#);
+//! # Ok(())
+//! # }
+//! ```
+
+use core::{fmt, marker::PhantomData};
+
+use ::serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, Visitor},
+    ser::SerializeStruct,
+};
+
+use crate::{FuzzyDate, FuzzyDateRange, PackedFuzzyDate};
+
+impl Serialize for FuzzyDate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            With::<Compact>::serialize_with(self, serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for FuzzyDate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(StrVisitor::new("an ISO fuzzy date string"))
+        } else {
+            With::<Compact>::deserialize_with(deserializer)
+        }
+    }
+}
+
+impl Serialize for FuzzyDateRange {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            With::<Compact>::serialize_with(self, serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for FuzzyDateRange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(StrVisitor::new("an ISO fuzzy date range string"))
+        } else {
+            With::<Compact>::deserialize_with(deserializer)
+        }
+    }
+}
+
+/// Parses a borrowed or transient string without copying it.
+struct StrVisitor<T> {
+    expecting: &'static str,
+    marker: PhantomData<T>,
+}
+
+impl<T> StrVisitor<T> {
+    const fn new(expecting: &'static str) -> Self {
+        Self {
+            expecting,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Visitor<'_> for StrVisitor<T>
+where
+    T: core::str::FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<T, E>
+    where
+        E: de::Error,
+    {
+        value.parse().map_err(E::custom)
+    }
+}
+
+#[doc(hidden)]
+pub mod private {
+    use super::{Deserializer, Serializer};
+
+    /// A representation selected by one of the adapter modules.
+    pub trait Repr {}
+
+    /// A value that can be written in the representation `R`.
+    pub trait With<R: Repr>: Sized {
+        fn serialize_with<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+        fn deserialize_with<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+    }
+}
+
+use private::{Repr, With};
+
+/// Serializes a value through `With<R>`, for use inside `Option`.
+struct SerializeWith<'a, R, T>(&'a T, PhantomData<R>);
+
+impl<R: Repr, T: With<R>> Serialize for SerializeWith<'_, R, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_with(serializer)
+    }
+}
+
+/// Deserializes a value through `With<R>`, for use inside `Option`.
+struct DeserializeWith<R, T>(T, PhantomData<R>);
+
+impl<'de, R: Repr, T: With<R>> Deserialize<'de> for DeserializeWith<R, T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize_with(deserializer).map(|value| Self(value, PhantomData))
+    }
+}
+
+impl<R: Repr, T: With<R>> With<R> for Option<T> {
+    fn serialize_with<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Some(value) => serializer.serialize_some(&SerializeWith(value, PhantomData::<R>)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    fn deserialize_with<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Option::<DeserializeWith<R, T>>::deserialize(deserializer)?.map(|value| value.0))
+    }
+}
+
+/// Defines an adapter module whose `serialize`/`deserialize` dispatch to the
+/// `With` impls for its marker type.
+macro_rules! adapter {
+    ($(#[$doc:meta])* $module:ident, $marker:ident) => {
+        #[doc(hidden)]
+        pub struct $marker;
+
+        impl Repr for $marker {}
+
+        $(#[$doc])*
+        pub mod $module {
+            use super::{$marker, Deserializer, Serializer, With};
+
+            /// Serializes `value` in this module's representation.
+            ///
+            /// # Errors
+            /// Returns the serializer's error.
+            pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                T: With<$marker>,
+                S: Serializer,
+            {
+                value.serialize_with(serializer)
+            }
+
+            /// Deserializes a value from this module's representation.
+            ///
+            /// # Errors
+            /// Returns the deserializer's error, including for values that
+            /// are not a valid date or range.
+            pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+            where
+                T: With<$marker>,
+                D: Deserializer<'de>,
+            {
+                T::deserialize_with(deserializer)
+            }
+        }
+    };
+}
+
+adapter!(
+    /// Dates as `[year, month, day]` arrays with `null` for missing
+    /// components, matching `FuzzyDate::to_columns`; ranges as a pair of them.
+    columns,
+    Columns
+);
+
+adapter!(
+    /// Dates as `{"year", "month", "day"}` maps omitting missing components
+    /// in human-readable formats; ranges as `{"start", "end"}` maps of them.
+    object,
+    Object
+);
+
+adapter!(
+    /// Dates as the `u32` of `PackedFuzzyDate::to_bits`; ranges as the
+    /// big-endian `u64` of `FuzzyDateRange::to_key`. Both sort like `Ord`.
+    compact,
+    Compact
+);
+
+type ColumnTuple = (u16, Option<u8>, Option<u8>);
+
+impl With<Columns> for FuzzyDate {
+    fn serialize_with<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_columns().serialize(serializer)
+    }
+
+    fn deserialize_with<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (year, month, day) = ColumnTuple::deserialize(deserializer)?;
+        Self::from_columns(year, month, day).map_err(de::Error::custom)
+    }
+}
+
+impl With<Columns> for FuzzyDateRange {
+    fn serialize_with<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.start().to_columns(), self.end().to_columns()).serialize(serializer)
+    }
+
+    fn deserialize_with<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let ((sy, sm, sd), (ey, em, ed)) = <(ColumnTuple, ColumnTuple)>::deserialize(deserializer)?;
+        Self::from_columns(sy, sm, sd, ey, em, ed).map_err(de::Error::custom)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "FuzzyDate", deny_unknown_fields)]
+struct DateObject {
+    year: u16,
+    #[serde(default)]
+    month: Option<u8>,
+    #[serde(default)]
+    day: Option<u8>,
+}
+
+impl Serialize for DateObject {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Missing components are omitted only in human-readable formats:
+        // formats such as postcard read every field in order and cannot tell
+        // that one was skipped.
+        let skip_missing = serializer.is_human_readable();
+        let mut object = serializer.serialize_struct("FuzzyDate", 3)?;
+        object.serialize_field("year", &self.year)?;
+        for (key, value) in [("month", self.month), ("day", self.day)] {
+            if skip_missing && value.is_none() {
+                object.skip_field(key)?;
+            } else {
+                object.serialize_field(key, &value)?;
+            }
+        }
+        object.end()
+    }
+}
+
+impl From<FuzzyDate> for DateObject {
+    fn from(date: FuzzyDate) -> Self {
+        let (year, month, day) = date.to_columns();
+        Self { year, month, day }
+    }
+}
+
+impl TryFrom<DateObject> for FuzzyDate {
+    type Error = crate::ParseError;
+
+    fn try_from(object: DateObject) -> Result<Self, Self::Error> {
+        Self::from_columns(object.year, object.month, object.day)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "FuzzyDateRange", deny_unknown_fields)]
+struct RangeObject {
+    start: DateObject,
+    end: DateObject,
+}
+
+impl With<Object> for FuzzyDate {
+    fn serialize_with<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        DateObject::from(*self).serialize(serializer)
+    }
+
+    fn deserialize_with<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::try_from(DateObject::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+impl With<Object> for FuzzyDateRange {
+    fn serialize_with<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        RangeObject {
+            start: self.start().into(),
+            end: self.end().into(),
+        }
+        .serialize(serializer)
+    }
+
+    fn deserialize_with<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let object = RangeObject::deserialize(deserializer)?;
+        let start = FuzzyDate::try_from(object.start).map_err(de::Error::custom)?;
+        let end = FuzzyDate::try_from(object.end).map_err(de::Error::custom)?;
+        Self::new(start, end).map_err(de::Error::custom)
+    }
+}
+
+impl With<Compact> for FuzzyDate {
+    fn serialize_with<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(PackedFuzzyDate::new(*self).to_bits())
+    }
+
+    fn deserialize_with<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        PackedFuzzyDate::from_bits(u32::deserialize(deserializer)?)
+            .map(PackedFuzzyDate::get)
+            .map_err(de::Error::custom)
+    }
+}
+
+impl With<Compact> for FuzzyDateRange {
+    fn serialize_with<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(u64::from_be_bytes(self.to_key()))
+    }
+
+    fn deserialize_with<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::from_key(u64::deserialize(deserializer)?.to_be_bytes()).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use crate::{
+        FuzzyDate, FuzzyDateRange,
+        test_utils::{fuzzy_day, fuzzy_month, fuzzy_year},
+    };
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        #[serde(with = "crate::serde::columns")]
+        columns: FuzzyDate,
+        #[serde(with = "crate::serde::object")]
+        object: FuzzyDate,
+        #[serde(with = "crate::serde::compact")]
+        compact: FuzzyDate,
+        #[serde(with = "crate::serde::columns")]
+        maybe: Option<FuzzyDate>,
+        #[serde(with = "crate::serde::object")]
+        missing: Option<FuzzyDate>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Ranges {
+        #[serde(with = "crate::serde::columns")]
+        columns: FuzzyDateRange,
+        #[serde(with = "crate::serde::object")]
+        object: Option<FuzzyDateRange>,
+        #[serde(with = "crate::serde::compact")]
+        compact: FuzzyDateRange,
+    }
+
+    fn range(start: FuzzyDate, end: FuzzyDate) -> FuzzyDateRange {
+        FuzzyDateRange::new(start, end).expect("expected valid test range")
+    }
+
+    #[test]
+    fn test_date_adapters() {
+        let record = Record {
+            columns: fuzzy_month(2026, 2),
+            object: fuzzy_month(2026, 2),
+            compact: fuzzy_day(2026, 2, 13),
+            maybe: Some(fuzzy_year(1991)),
+            missing: None,
+        };
+        let value = serde_json::to_value(&record).expect("expected record to serialize");
+        assert_eq!(
+            value,
+            json!({
+                "columns": [2026, 2, null],
+                "object": {"year": 2026, "month": 2},
+                "compact": u32::from_be_bytes(fuzzy_day(2026, 2, 13).to_key()),
+                "maybe": [1991, null, null],
+                "missing": null,
+            })
+        );
+        let parsed: Record = serde_json::from_value(value).expect("expected record to deserialize");
+        assert_eq!(parsed, record);
+    }
+
+    #[test]
+    fn test_range_adapters() {
+        let ranges = Ranges {
+            columns: range(fuzzy_year(2020), fuzzy_day(2026, 2, 13)),
+            object: Some(range(fuzzy_month(2020, 3), fuzzy_year(2026))),
+            compact: range(fuzzy_year(2020), fuzzy_month(2026, 2)),
+        };
+        let value = serde_json::to_value(&ranges).expect("expected ranges to serialize");
+        assert_eq!(value["columns"], json!([[2020, null, null], [2026, 2, 13]]));
+        assert_eq!(
+            value["object"],
+            json!({"start": {"year": 2020, "month": 3}, "end": {"year": 2026}})
+        );
+        let parsed: Ranges = serde_json::from_value(value).expect("expected ranges to deserialize");
+        assert_eq!(parsed, ranges);
+    }
+
+    #[test]
+    fn test_adapter_validation() {
+        for value in [
+            json!({"columns": [2023, 2, 29]}),
+            json!({"columns": [2023, null, 1]}),
+            json!({"object": {"year": 2023, "month": 13}}),
+            json!({"object": {"year": 2023, "week": 1}}),
+            json!({"compact": 0}),
+        ] {
+            #[derive(Debug, Deserialize)]
+            #[allow(dead_code)]
+            struct Field {
+                #[serde(with = "crate::serde::columns", default)]
+                columns: Option<FuzzyDate>,
+                #[serde(with = "crate::serde::object", default)]
+                object: Option<FuzzyDate>,
+                #[serde(with = "crate::serde::compact", default)]
+                compact: Option<FuzzyDate>,
+            }
+            assert!(
+                serde_json::from_value::<Field>(value.clone()).is_err(),
+                "{value}"
+            );
+        }
+
+        let reversed = json!({"start": {"year": 2026}, "end": {"year": 2020}});
+        assert!(crate::serde::object::deserialize::<FuzzyDateRange, _>(reversed).is_err());
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Plain {
+        date: FuzzyDate,
+        range: FuzzyDateRange,
+        missing: Option<FuzzyDate>,
+    }
+
+    fn plain() -> Plain {
+        Plain {
+            date: fuzzy_month(2026, 2),
+            range: range(fuzzy_year(2020), fuzzy_day(2026, 2, 13)),
+            missing: None,
+        }
+    }
+
+    #[test]
+    fn test_human_readable_formats() {
+        let value = plain();
+
+        let json = serde_json::to_string(&value).expect("expected JSON");
+        assert_eq!(
+            json,
+            r#"{"date":"2026-02","range":"2020/2026-02-13","missing":null}"#
+        );
+        assert_eq!(serde_json::from_str::<Plain>(&json).ok(), Some(plain()));
+
+        let toml = toml::to_string(&value).expect("expected TOML");
+        assert_eq!(toml, "date = \"2026-02\"\nrange = \"2020/2026-02-13\"\n");
+        assert_eq!(toml::from_str::<Plain>(&toml).ok(), Some(plain()));
+
+        let yaml = serde_yaml_ng::to_string(&value).expect("expected YAML");
+        assert_eq!(
+            yaml,
+            "date: 2026-02\nrange: 2020/2026-02-13\nmissing: null\n"
+        );
+        assert_eq!(serde_yaml_ng::from_str::<Plain>(&yaml).ok(), Some(plain()));
+    }
+
+    #[test]
+    fn test_binary_formats() {
+        let value = plain();
+        let config = bincode::config::standard();
+
+        let encoded = bincode::serde::encode_to_vec(&value, config).expect("expected bincode");
+        // Varint u32 and u64 with no string lengths: far below the 22 bytes
+        // of ISO text alone.
+        assert!(encoded.len() <= 16, "{} bytes", encoded.len());
+        let (decoded, _): (Plain, usize) =
+            bincode::serde::decode_from_slice(&encoded, config).expect("expected bincode");
+        assert_eq!(decoded, value);
+
+        let encoded = postcard::to_allocvec(&value).expect("expected postcard");
+        assert!(encoded.len() <= 16, "{} bytes", encoded.len());
+        assert_eq!(postcard::from_bytes::<Plain>(&encoded).ok(), Some(plain()));
+
+        // The binary form is the compact adapter's integer
+        let packed = postcard::to_allocvec(&fuzzy_month(2026, 2)).expect("expected postcard");
+        let bits = postcard::to_allocvec(&u32::from_be_bytes(fuzzy_month(2026, 2).to_key()))
+            .expect("expected postcard");
+        assert_eq!(packed, bits);
+        assert!(postcard::from_bytes::<FuzzyDate>(&[0]).is_err());
+    }
+
+    #[test]
+    fn test_adapters_in_binary_formats() {
+        let record = Record {
+            columns: fuzzy_month(2026, 2),
+            object: fuzzy_year(2026),
+            compact: fuzzy_day(2026, 2, 13),
+            maybe: None,
+            missing: Some(fuzzy_month(1991, 8)),
+        };
+        let encoded = postcard::to_allocvec(&record).expect("expected postcard");
+        assert_eq!(postcard::from_bytes::<Record>(&encoded).ok(), Some(record));
+
+        let ranges = Ranges {
+            columns: range(fuzzy_year(2020), fuzzy_day(2026, 2, 13)),
+            object: Some(range(fuzzy_year(2020), fuzzy_month(2026, 2))),
+            compact: range(fuzzy_year(2020), fuzzy_year(2026)),
+        };
+        let config = bincode::config::standard();
+        let encoded = bincode::serde::encode_to_vec(&ranges, config).expect("expected bincode");
+        let decoded = bincode::serde::decode_from_slice::<Ranges, _>(&encoded, config)
+            .map(|(decoded, _)| decoded);
+        assert_eq!(decoded.ok(), Some(ranges));
+    }
+
+    #[test]
+    fn test_borrowed_str() {
+        // `from_str` hands the visitor a slice of the input, while
+        // `from_reader` goes through a transient buffer.
+        let date: FuzzyDate = serde_json::from_str(r#""2026-02""#).expect("expected date");
+        assert_eq!(date, fuzzy_month(2026, 2));
+        let date: FuzzyDate =
+            serde_json::from_reader(&br#""2026-02-13""#[..]).expect("expected date");
+        assert_eq!(date, fuzzy_day(2026, 2, 13));
+        let escaped: FuzzyDateRange =
+            serde_json::from_str(r#""2020\/2026""#).expect("expected escaped range");
+        assert_eq!(escaped, range(fuzzy_year(2020), fuzzy_year(2026)));
+
+        let err = serde_json::from_str::<FuzzyDate>("2026").expect_err("expected type error");
+        assert!(
+            err.to_string().contains("an ISO fuzzy date string"),
+            "{err}"
+        );
+    }
+}
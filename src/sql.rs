@@ -0,0 +1,164 @@
+//! SQL expressions over the column layouts of [`FuzzyDate::to_columns`] and
+//! [`FuzzyDateRange::to_columns`].
+//!
+//! The expressions use `SQLite`'s `printf` and integer arithmetic and evaluate to
+//! ISO `YYYY-MM-DD` text, which compares chronologically, so range filters can
+//! run in the database.
+//!
+//! [`FuzzyDate::to_columns`]: crate::FuzzyDate::to_columns
+//! [`FuzzyDateRange::to_columns`]: crate::FuzzyDateRange::to_columns
+
+use crate::prelude::*;
+
+/// Names of the year, month and day columns that store one fuzzy date.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SqlColumns {
+    year: String,
+    month: String,
+    day: String,
+}
+
+impl SqlColumns {
+    /// Columns with explicit names.
+    pub fn new(year: impl Into<String>, month: impl Into<String>, day: impl Into<String>) -> Self {
+        Self {
+            year: year.into(),
+            month: month.into(),
+            day: day.into(),
+        }
+    }
+
+    /// Columns named `{prefix}_year`, `{prefix}_month` and `{prefix}_day`.
+    pub fn prefixed(prefix: &str) -> Self {
+        Self::new(
+            format!("{prefix}_year"),
+            format!("{prefix}_month"),
+            format!("{prefix}_day"),
+        )
+    }
+
+    /// The year, month and day column names.
+    pub fn names(&self) -> [&str; 3] {
+        [&self.year, &self.month, &self.day]
+    }
+
+    /// The quoted columns separated by commas, in `to_columns` order, for
+    /// `SELECT` and `INSERT` lists.
+    pub fn select_list(&self) -> String {
+        self.names().map(quote).join(", ")
+    }
+
+    /// Expression for [`FuzzyDate::lower_bound`](crate::FuzzyDate::lower_bound)
+    /// as `YYYY-MM-DD` text.
+    pub fn lower_bound_sql(&self) -> String {
+        let [year, month, day] = self.names().map(quote);
+        format!("printf('%04d-%02d-%02d', {year}, COALESCE({month}, 1), COALESCE({day}, 1))")
+    }
+
+    /// Expression for
+    /// [`FuzzyDate::upper_bound_inclusive`](crate::FuzzyDate::upper_bound_inclusive)
+    /// as `YYYY-MM-DD` text.
+    pub fn upper_bound_sql(&self) -> String {
+        let [year, month, day] = self.names().map(quote);
+        // Integer leap-year rule rather than date(), which is limited to
+        // 0000-9999 and so cannot step past December 9999
+        format!(
+            "printf('%04d-%02d-%02d', {year}, COALESCE({month}, 12), CASE \
+             WHEN {day} IS NOT NULL THEN {day} \
+             WHEN {month} IS NULL OR {month} IN (1, 3, 5, 7, 8, 10, 12) THEN 31 \
+             WHEN {month} IN (4, 6, 9, 11) THEN 30 \
+             WHEN ({year} % 4 = 0 AND {year} % 100 <> 0) OR {year} % 400 = 0 THEN 29 \
+             ELSE 28 END)"
+        )
+    }
+}
+
+/// The start and end columns that store one fuzzy date range.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SqlRangeColumns {
+    start: SqlColumns,
+    end: SqlColumns,
+}
+
+impl SqlRangeColumns {
+    /// Columns for the start and end of the range.
+    pub const fn new(start: SqlColumns, end: SqlColumns) -> Self {
+        Self { start, end }
+    }
+
+    /// Columns named `{prefix}_start_year` through `{prefix}_end_day`.
+    pub fn prefixed(prefix: &str) -> Self {
+        Self::new(
+            SqlColumns::prefixed(&format!("{prefix}_start")),
+            SqlColumns::prefixed(&format!("{prefix}_end")),
+        )
+    }
+
+    /// The start columns.
+    pub const fn start(&self) -> &SqlColumns {
+        &self.start
+    }
+
+    /// The end columns.
+    pub const fn end(&self) -> &SqlColumns {
+        &self.end
+    }
+
+    /// All six quoted columns separated by commas, in `to_columns` order.
+    pub fn select_list(&self) -> String {
+        format!("{}, {}", self.start.select_list(), self.end.select_list())
+    }
+
+    /// Expression for the range's earliest date as `YYYY-MM-DD` text.
+    pub fn lower_bound_sql(&self) -> String {
+        self.start.lower_bound_sql()
+    }
+
+    /// Expression for the range's latest date (inclusive) as `YYYY-MM-DD` text.
+    pub fn upper_bound_sql(&self) -> String {
+        self.end.upper_bound_sql()
+    }
+}
+
+/// Quotes an identifier, doubling any embedded quotes.
+fn quote(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefixed_names() {
+        let columns = SqlRangeColumns::prefixed("active");
+        assert_eq!(
+            columns.start().names(),
+            [
+                "active_start_year",
+                "active_start_month",
+                "active_start_day"
+            ]
+        );
+        assert_eq!(
+            columns.select_list(),
+            "\"active_start_year\", \"active_start_month\", \"active_start_day\", \
+             \"active_end_year\", \"active_end_month\", \"active_end_day\""
+        );
+    }
+
+    #[test]
+    fn test_quoting() {
+        let columns = SqlColumns::new("y\"", "m", "d");
+        assert_eq!(columns.select_list(), "\"y\"\"\", \"m\", \"d\"");
+    }
+
+    #[test]
+    fn test_lower_bound_sql() {
+        assert_eq!(
+            SqlColumns::prefixed("born").lower_bound_sql(),
+            "printf('%04d-%02d-%02d', \"born_year\", COALESCE(\"born_month\", 1), \
+             COALESCE(\"born_day\", 1))"
+        );
+    }
+}
@@ -0,0 +1,240 @@
+//! `sqlx` integration.
+//!
+//! `FuzzyDate` and `FuzzyDateRange` encode and decode as ISO text on any
+//! database whose text type is `str`, and `FromRow` reads the integer column
+//! layouts of [`to_integer_columns`](FuzzyDate::to_integer_columns) from the
+//! start of a row.
+
+use ::sqlx::{
+    ColumnIndex, Database, Decode, Encode, Error, FromRow, Row, Type, encode::IsNull,
+    error::BoxDynError,
+};
+
+use crate::{FuzzyDate, FuzzyDateRange};
+
+/// Encodes and decodes a type as text through its `Display`/`FromStr` forms.
+macro_rules! impl_text {
+    ($ty:ty) => {
+        impl<DB: Database> Type<DB> for $ty
+        where
+            str: Type<DB>,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <str as Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <str as Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl<'q, DB: Database> Encode<'q, DB> for $ty
+        where
+            String: Encode<'q, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+            ) -> Result<IsNull, BoxDynError> {
+                self.to_string().encode(buf)
+            }
+        }
+
+        impl<'r, DB: Database> Decode<'r, DB> for $ty
+        where
+            &'r str: Decode<'r, DB>,
+        {
+            fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+                Ok(<&str as Decode<DB>>::decode(value)?.parse()?)
+            }
+        }
+    };
+}
+
+impl_text!(FuzzyDate);
+impl_text!(FuzzyDateRange);
+
+/// Reads the year, month and day columns starting at column `first`.
+fn date_from_columns<'r, R>(row: &'r R, first: usize) -> Result<FuzzyDate, Error>
+where
+    R: Row,
+    usize: ColumnIndex<R>,
+    i32: Decode<'r, R::Database> + Type<R::Database>,
+{
+    FuzzyDate::from_integer_columns(
+        row.try_get(first)?,
+        row.try_get(first + 1)?,
+        row.try_get(first + 2)?,
+    )
+    .map_err(|err| Error::ColumnDecode {
+        index: first.to_string(),
+        source: Box::new(err),
+    })
+}
+
+/// Reads the first three columns of the row as year, month and day.
+impl<'r, R> FromRow<'r, R> for FuzzyDate
+where
+    R: Row,
+    usize: ColumnIndex<R>,
+    i32: Decode<'r, R::Database> + Type<R::Database>,
+{
+    fn from_row(row: &'r R) -> Result<Self, Error> {
+        date_from_columns(row, 0)
+    }
+}
+
+/// Reads the first six columns of the row as the start and end year, month
+/// and day.
+impl<'r, R> FromRow<'r, R> for FuzzyDateRange
+where
+    R: Row,
+    usize: ColumnIndex<R>,
+    i32: Decode<'r, R::Database> + Type<R::Database>,
+{
+    fn from_row(row: &'r R) -> Result<Self, Error> {
+        Self::new(date_from_columns(row, 0)?, date_from_columns(row, 3)?).map_err(|err| {
+            Error::ColumnDecode {
+                index: 0.to_string(),
+                source: Box::new(err),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::sqlx::{Connection, SqliteConnection, query, query_as, query_scalar};
+
+    use super::*;
+    use crate::test_utils::{fuzzy_day, fuzzy_month, fuzzy_year};
+
+    async fn connection() -> SqliteConnection {
+        let mut conn = SqliteConnection::connect("sqlite::memory:")
+            .await
+            .expect("expected in-memory database");
+        query(
+            "CREATE TABLE people (
+                born TEXT NOT NULL, active TEXT NOT NULL,
+                born_year INTEGER NOT NULL, born_month INTEGER, born_day INTEGER,
+                active_start_year INTEGER NOT NULL, active_start_month INTEGER, active_start_day INTEGER,
+                active_end_year INTEGER NOT NULL, active_end_month INTEGER, active_end_day INTEGER
+            )",
+        )
+        .execute(&mut conn)
+        .await
+        .expect("expected table");
+        conn
+    }
+
+    async fn insert(conn: &mut SqliteConnection, born: FuzzyDate, active: FuzzyDateRange) {
+        let (by, bm, bd) = born.to_integer_columns();
+        let (sy, sm, sd, ey, em, ed) = active.to_integer_columns();
+        query("INSERT INTO people VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
+            .bind(born)
+            .bind(active)
+            .bind(by)
+            .bind(bm)
+            .bind(bd)
+            .bind(sy)
+            .bind(sm)
+            .bind(sd)
+            .bind(ey)
+            .bind(em)
+            .bind(ed)
+            .execute(conn)
+            .await
+            .expect("expected insert");
+    }
+
+    fn range(start: FuzzyDate, end: FuzzyDate) -> FuzzyDateRange {
+        FuzzyDateRange::new(start, end).expect("expected valid test range")
+    }
+
+    #[tokio::test]
+    async fn test_text_round_trip() {
+        let mut conn = connection().await;
+        let active = range(fuzzy_month(2020, 3), fuzzy_day(2026, 2, 13));
+        insert(&mut conn, fuzzy_month(1991, 8), active).await;
+
+        let stored: String = query_scalar("SELECT born FROM people")
+            .fetch_one(&mut conn)
+            .await
+            .expect("expected row");
+        assert_eq!(stored, "1991-08");
+
+        let (born, loaded): (FuzzyDate, FuzzyDateRange) =
+            query_as("SELECT born, active FROM people")
+                .fetch_one(&mut conn)
+                .await
+                .expect("expected row");
+        assert_eq!(born, fuzzy_month(1991, 8));
+        assert_eq!(loaded, active);
+
+        let count: i64 = query_scalar("SELECT COUNT(*) FROM people WHERE born = ?")
+            .bind(fuzzy_month(1991, 8))
+            .fetch_one(&mut conn)
+            .await
+            .expect("expected count");
+        assert_eq!(count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_column_round_trip() {
+        let mut conn = connection().await;
+        let active = range(fuzzy_year(2010), fuzzy_day(2026, 2, 13));
+        insert(&mut conn, fuzzy_day(1991, 8, 15), active).await;
+
+        let born: FuzzyDate = query_as("SELECT born_year, born_month, born_day FROM people")
+            .fetch_one(&mut conn)
+            .await
+            .expect("expected row");
+        assert_eq!(born, fuzzy_day(1991, 8, 15));
+
+        let loaded: FuzzyDateRange = query_as(
+            "SELECT active_start_year, active_start_month, active_start_day,
+                    active_end_year, active_end_month, active_end_day FROM people",
+        )
+        .fetch_one(&mut conn)
+        .await
+        .expect("expected row");
+        assert_eq!(loaded, active);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_values() {
+        let mut conn = connection().await;
+        insert(
+            &mut conn,
+            fuzzy_year(1991),
+            range(fuzzy_year(2010), fuzzy_year(2020)),
+        )
+        .await;
+        query("UPDATE people SET born = '1991-13', born_month = 13, active_start_year = 2030")
+            .execute(&mut conn)
+            .await
+            .expect("expected update");
+
+        assert!(
+            query_scalar::<_, FuzzyDate>("SELECT born FROM people")
+                .fetch_one(&mut conn)
+                .await
+                .is_err()
+        );
+        assert!(matches!(
+            query_as::<_, FuzzyDate>("SELECT born_year, born_month, born_day FROM people")
+                .fetch_one(&mut conn)
+                .await,
+            Err(Error::ColumnDecode { .. })
+        ));
+        assert!(matches!(
+            query_as::<_, FuzzyDateRange>(
+                "SELECT active_start_year, active_start_month, active_start_day,
+                        active_end_year, active_end_month, active_end_day FROM people",
+            )
+            .fetch_one(&mut conn)
+            .await,
+            Err(Error::ColumnDecode { .. })
+        ));
+    }
+}
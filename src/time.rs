@@ -0,0 +1,32 @@
+//! `time` integration.
+
+use ::time::{Date, Month};
+
+use crate::calendar::{CalendarDate, impl_calendar_date};
+
+impl_calendar_date!(Date);
+
+impl CalendarDate for Date {
+    fn from_ymd(year: u16, month: u8, day: u8) -> Self {
+        Month::try_from(month)
+            .and_then(|month| Self::from_calendar_date(year.into(), month, day))
+            .unwrap_or_else(|_| unreachable!("fuzzy date components are always a valid Date"))
+    }
+
+    fn to_ymd(self) -> (i32, u8, u8) {
+        (self.year(), self.month().into(), self.day())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::test_utils::check_calendar_date;
+
+    #[test]
+    fn test_calendar_date() {
+        let year_zero =
+            Date::from_calendar_date(0, Month::January, 1).expect("expected valid test date");
+        check_calendar_date(year_zero);
+    }
+}
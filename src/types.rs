@@ -1,4 +1,4 @@
-use std::{
+use core::{
     fmt,
     num::{NonZeroU8, NonZeroU16},
 };
@@ -25,12 +25,11 @@ impl Year {
     ///
     /// # Errors
     /// Returns `ParseError::InvalidYear` if the value is 0 or > `MAX_YEAR`.
-    pub fn new(value: u16) -> Result<Self, ParseError> {
-        let non_zero = NonZeroU16::new(value).ok_or(ParseError::InvalidYear(value))?;
-        if value > MAX_YEAR {
-            return Err(ParseError::InvalidYear(value));
+    pub const fn new(value: u16) -> Result<Self, ParseError> {
+        match checked_year(value) {
+            Some(year) => Ok(year),
+            None => Err(ParseError::InvalidYear(value)),
         }
-        Ok(Self(non_zero))
     }
 
     /// Returns the year value as u16
@@ -86,12 +85,11 @@ impl Month {
     ///
     /// # Errors
     /// Returns `ParseError::InvalidMonth` if the value is 0 or > `MAX_MONTH`.
-    pub fn new(value: u8) -> Result<Self, ParseError> {
-        let non_zero = NonZeroU8::new(value).ok_or(ParseError::InvalidMonth(value))?;
-        if value > MAX_MONTH {
-            return Err(ParseError::InvalidMonth(value));
+    pub const fn new(value: u8) -> Result<Self, ParseError> {
+        match checked_month(value) {
+            Some(month) => Ok(month),
+            None => Err(ParseError::InvalidMonth(value)),
         }
-        Ok(Self(non_zero))
     }
 
     /// Returns the month value as u8
@@ -148,27 +146,19 @@ impl Day {
     /// # Errors
     /// Returns `ParseError::InvalidMonth` if `month` is 0 or greater than 12.
     /// Returns `ParseError::InvalidDay` if the value is 0 or invalid for the given year and month.
-    pub fn new(value: u8, year: u16, month: u8) -> Result<Self, ParseError> {
+    pub const fn new(value: u8, year: u16, month: u8) -> Result<Self, ParseError> {
         if month == 0 || month > MAX_MONTH {
             return Err(ParseError::InvalidMonth(month));
         }
 
-        let non_zero = NonZeroU8::new(value).ok_or(ParseError::InvalidDay {
-            month,
-            day: value,
-            year,
-        })?;
-
-        let max_day = days_in_month(year, month);
-        if value > max_day {
-            return Err(ParseError::InvalidDay {
+        match checked_day(value, year, month) {
+            Some(day) => Ok(day),
+            None => Err(ParseError::InvalidDay {
                 month,
                 day: value,
                 year,
-            });
+            }),
         }
-
-        Ok(Self(non_zero))
     }
 
     /// Returns the day value as u8
@@ -218,6 +208,28 @@ impl fmt::Display for Day {
     }
 }
 
+/// The level of detail a [`FuzzyDate`](crate::FuzzyDate) carries, from
+/// coarsest to finest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Precision {
+    /// Year only
+    Year,
+    /// Month and year
+    Month,
+    /// Full date
+    Day,
+}
+
+impl fmt::Display for Precision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Year => "year",
+            Self::Month => "month",
+            Self::Day => "day",
+        })
+    }
+}
+
 // Helper functions
 
 pub const fn is_leap_year(year: u16) -> bool {
@@ -235,6 +247,34 @@ pub const fn days_in_month(year: u16, month: u8) -> u8 {
     }
 }
 
+// `ParseError` may own a `String`, and a `const fn` cannot drop one, so const
+// callers that discard the error build components through these instead.
+
+pub const fn checked_year(value: u16) -> Option<Year> {
+    match NonZeroU16::new(value) {
+        Some(non_zero) if value <= MAX_YEAR => Some(Year(non_zero)),
+        _ => None,
+    }
+}
+
+pub const fn checked_month(value: u8) -> Option<Month> {
+    match NonZeroU8::new(value) {
+        Some(non_zero) if value <= MAX_MONTH => Some(Month(non_zero)),
+        _ => None,
+    }
+}
+
+/// `None` if `month` or `value` is out of range for `year`.
+pub const fn checked_day(value: u8, year: u16, month: u8) -> Option<Day> {
+    if month == 0 || month > MAX_MONTH {
+        return None;
+    }
+    match NonZeroU8::new(value) {
+        Some(non_zero) if value <= days_in_month(year, month) => Some(Day(non_zero)),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,43 @@
+//! Inputs shared by the unit tests in `src/bytes.rs` and the integration
+//! tests.
+
+/// Inputs covering every success and failure path of `FromStr` and
+/// `parse_bytes`.
+pub const PARSE_CASES: &[&str] = &[
+    "1991",
+    "1991-08",
+    "1991-08-15",
+    " 1991 - 08 - 15 ",
+    "+1991-+8",
+    "02021",
+    "08/1991",
+    "8/15/1991",
+    "08/15/0091",
+    "08/91",
+    "",
+    "   ",
+    "0",
+    "10000",
+    "65536",
+    "1991-13",
+    "1991-256",
+    "1991-02-30",
+    "02/29/2021",
+    "13/1991",
+    "1991-08/15",
+    "08/15-1991",
+    "2000-01-15-23",
+    "01/15/2000/extra",
+    "199A",
+    "02/XX/2020",
+    "1991-08-XX",
+    "08 / 1991",
+    "123/1991",
+    "08/123/1991",
+    "08/",
+    "/1991",
+    "08//1991",
+    "1991-",
+    "-08",
+    "+",
+];
@@ -0,0 +1,66 @@
+//! `parse_bytes` never allocates.
+//!
+//! Kept in its own test binary so the counting global allocator does not
+//! affect any other tests.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    hint::black_box,
+};
+
+use fuzzy_date::{FuzzyDate, FuzzyDateRange};
+
+mod common;
+
+/// Counts allocations made by the current thread, so tests running in
+/// parallel do not interfere with each other.
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        // SAFETY: forwarded unchanged to the system allocator
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: forwarded unchanged to the system allocator
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocations_during(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+const RANGES: &[&str] = &["1990-01-15/2025-12", "2000/1990", "1/2/3", "1990/XX"];
+
+#[test]
+fn test_parse_bytes_never_allocates() {
+    for input in common::PARSE_CASES {
+        let allocations = allocations_during(|| {
+            let _ = black_box(FuzzyDate::parse_bytes(input.as_bytes()));
+        });
+        assert_eq!(allocations, 0, "input: {input:?}");
+    }
+}
+
+#[test]
+fn test_range_parse_bytes_never_allocates() {
+    for input in RANGES {
+        let allocations = allocations_during(|| {
+            let _ = black_box(FuzzyDateRange::parse_bytes(input.as_bytes()));
+        });
+        assert_eq!(allocations, 0, "input: {input:?}");
+    }
+}